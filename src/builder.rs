@@ -0,0 +1,185 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::BTreeVec;
+use super::extend::Tail;
+use crate::{Allocator, Global};
+
+/// Builds a [`BTreeVec`] from items in order, packing each leaf with a
+/// configurable number of items.
+///
+/// Vectors built by repeatedly calling [`BTreeVec::push`] have leaves that
+/// are only about half full. Packing leaves more tightly uses less memory and
+/// makes iteration faster, which is useful for large vectors that are rarely
+/// modified after they are created.
+///
+/// By default, leaves are filled completely; see [`Self::leaf_fill`].
+///
+/// # Mathematical variables
+///
+/// As with [`BTreeVec`], *n* refers to the number of items added so far.
+pub struct BTreeVecBuilder<T, const B: usize = 12, A: Allocator = Global> {
+    vec: BTreeVec<T, B, A>,
+    /// The last leaf in `vec`.
+    tail: Tail<T, B>,
+    fill: usize,
+}
+
+// SAFETY: `BTreeVecBuilder` owns its data, so it can be sent to another
+// thread.
+unsafe impl<T, const B: usize, A> Send for BTreeVecBuilder<T, B, A>
+where
+    T: Send,
+    A: Allocator,
+{
+}
+
+// SAFETY: `BTreeVecBuilder` owns its data and provides access to it only
+// through standard borrows.
+unsafe impl<T, const B: usize, A> Sync for BTreeVecBuilder<T, B, A>
+where
+    T: Sync,
+    A: Allocator,
+{
+}
+
+impl<T> BTreeVecBuilder<T> {
+    /// Creates a new [`BTreeVecBuilder`]. Note that this function is
+    /// implemented only for the default value of `B`; see [`Self::create`]
+    /// for an equivalent that works with all values of `B`.
+    pub fn new() -> Self {
+        Self::create()
+    }
+}
+
+impl<T, A: Allocator> BTreeVecBuilder<T, 12, A> {
+    #[cfg_attr(
+        not(any(feature = "allocator_api", feature = "allocator-fallback")),
+        doc(hidden)
+    )]
+    /// Creates a new [`BTreeVecBuilder`] with the given allocator. Note that
+    /// this function is implemented only for the default value of `B`; see
+    /// [`Self::create_in`] for an equivalent that works with all values of
+    /// `B`.
+    pub fn new_in(alloc: A) -> Self {
+        Self::create_in(alloc)
+    }
+}
+
+impl<T, const B: usize> BTreeVecBuilder<T, B> {
+    /// Creates a new [`BTreeVecBuilder`]. This function exists because
+    /// [`BTreeVecBuilder::new`] is implemented only for the default value of
+    /// `B`.
+    pub fn create() -> Self {
+        Self::create_in(Global)
+    }
+}
+
+impl<T, const B: usize, A: Allocator> BTreeVecBuilder<T, B, A> {
+    #[cfg_attr(
+        not(any(feature = "allocator_api", feature = "allocator-fallback")),
+        doc(hidden)
+    )]
+    /// Creates a new [`BTreeVecBuilder`] with the given allocator. This
+    /// function exists because [`BTreeVecBuilder::new_in`] is implemented only
+    /// for the default value of `B`.
+    pub fn create_in(alloc: A) -> Self {
+        Self {
+            vec: BTreeVec::create_in(alloc),
+            tail: Tail::empty(),
+            fill: B,
+        }
+    }
+
+    /// Sets the number of items placed in each leaf. Only the last two leaves
+    /// may end up with a different number of items, as the last leaf may be
+    /// refilled from the one before it when the vector is built.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fill` is less than `B / 2` or greater than `B`.
+    pub fn leaf_fill(mut self, fill: usize) -> Self {
+        assert!(fill >= B / 2 && fill <= B);
+        self.fill = fill;
+        self
+    }
+
+    /// Gets the number of items added so far.
+    ///
+    /// # Time complexity
+    ///
+    /// Constant.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Checks whether no items have been added.
+    ///
+    /// # Time complexity
+    ///
+    /// Constant.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Adds `item` after all previously added items.
+    ///
+    /// # Time complexity
+    ///
+    /// Amortized constant; worst-case Θ(log *n*).
+    pub fn push(&mut self, item: T) {
+        self.tail.push(&mut self.vec, item, self.fill);
+    }
+
+    /// Builds the vector.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn build(self) -> BTreeVec<T, B, A> {
+        let Self {
+            mut vec,
+            mut tail,
+            ..
+        } = self;
+        tail.finish(&mut vec);
+        vec
+    }
+}
+
+impl<T, const B: usize, A> Default for BTreeVecBuilder<T, B, A>
+where
+    A: Allocator + Default,
+{
+    fn default() -> Self {
+        Self::create_in(A::default())
+    }
+}
+
+impl<T, const B: usize, A> Extend<T> for BTreeVecBuilder<T, B, A>
+where
+    A: Allocator,
+{
+    /// # Time complexity
+    ///
+    /// Θ(*k*), where *k* is the number of items added.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::BTreeVec;
+use super::extend::Extender;
+use crate::Allocator;
+
+/// Concatenates a sequence of [`BTreeVec`]s into a single vector.
+///
+/// This trait is implemented for vectors of vectors, whose items are moved,
+/// and for slices of vectors, whose items are cloned. Either way, the items
+/// are placed in a new vector with fully packed leaves. For vectors of
+/// vectors that use the global allocator, [`BTreeVec::concat_nodes`] and
+/// [`BTreeVec::join_nodes`] join the inner trees together instead, without
+/// moving any items.
+///
+/// # Mathematical variables
+///
+/// *k* refers to the number of vectors, *n* refers to the total number of
+/// items in the vectors, and *s* refers to the length of the separator.
+pub trait Concat<T> {
+    /// The type of the concatenated vector.
+    type Output;
+
+    /// Concatenates the vectors into a single vector.
+    fn concat(self) -> Self::Output;
+
+    /// Concatenates the vectors into a single vector, inserting a clone of
+    /// the items in `separator` between each pair of adjacent vectors.
+    fn join(self, separator: &[T]) -> Self::Output
+    where
+        T: Clone;
+}
+
+impl<T, const B: usize, const B2: usize, A, A2> Concat<T>
+    for BTreeVec<BTreeVec<T, B, A>, B2, A2>
+where
+    A: Allocator,
+    A2: Allocator,
+{
+    type Output = BTreeVec<T, B>;
+
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    fn concat(self) -> Self::Output {
+        let mut vec = BTreeVec::create();
+        let mut extender = Extender::new(&mut vec);
+        for other in self {
+            other.into_iter().for_each(|item| extender.push(item));
+        }
+        drop(extender);
+        vec
+    }
+
+    /// # Time complexity
+    ///
+    /// Θ(*n* + *ks*).
+    fn join(self, separator: &[T]) -> Self::Output
+    where
+        T: Clone,
+    {
+        let mut vec = BTreeVec::create();
+        let mut extender = Extender::new(&mut vec);
+        for (i, other) in self.into_iter().enumerate() {
+            if i > 0 {
+                separator.iter().for_each(|item| extender.push(item.clone()));
+            }
+            other.into_iter().for_each(|item| extender.push(item));
+        }
+        drop(extender);
+        vec
+    }
+}
+
+impl<T, const B: usize, A> Concat<T> for &[BTreeVec<T, B, A>]
+where
+    T: Clone,
+    A: Allocator,
+{
+    type Output = BTreeVec<T, B>;
+
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    fn concat(self) -> Self::Output {
+        self.iter().flatten().cloned().collect()
+    }
+
+    /// # Time complexity
+    ///
+    /// Θ(*n* + *ks*).
+    fn join(self, separator: &[T]) -> Self::Output {
+        let mut vec = BTreeVec::create();
+        let mut extender = Extender::new(&mut vec);
+        for (i, other) in self.iter().enumerate() {
+            if i > 0 {
+                separator.iter().for_each(|item| extender.push(item.clone()));
+            }
+            other.iter().for_each(|item| extender.push(item.clone()));
+        }
+        drop(extender);
+        vec
+    }
+}
+
+impl<T, const B: usize, const B2: usize, A2> BTreeVec<BTreeVec<T, B>, B2, A2>
+where
+    A2: Allocator,
+{
+    /// Concatenates the inner vectors into a single vector, like
+    /// [`Concat::concat`].
+    ///
+    /// No items are moved; instead, the tree of each inner vector is joined
+    /// onto the output with [`BTreeVec::append_nodes`]. Like that method,
+    /// only available with the global allocator.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* log *n*), where *k* is the number of inner vectors and *n* is
+    /// the total number of items in them.
+    pub fn concat_nodes(self) -> BTreeVec<T, B> {
+        let mut vec = BTreeVec::create();
+        for mut other in self {
+            vec.append_nodes(&mut other);
+        }
+        vec
+    }
+
+    /// Concatenates the inner vectors into a single vector, inserting a
+    /// clone of the items in `separator` between each pair of adjacent
+    /// vectors, like [`Concat::join`]. See [`Self::concat_nodes`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* (*s* + log *n*)), where *k* is the number of inner vectors, *n*
+    /// is the total number of items in them, and *s* is the length of
+    /// `separator`.
+    pub fn join_nodes(self, separator: &[T]) -> BTreeVec<T, B>
+    where
+        T: Clone,
+    {
+        let mut vec = BTreeVec::create();
+        for (i, mut other) in self.into_iter().enumerate() {
+            if i > 0 {
+                vec.extend(separator.iter().cloned());
+            }
+            vec.append_nodes(&mut other);
+        }
+        vec
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Copying of items between overlapping ranges of a tree.
+
+use super::node::{Mutable, Node, NodeRef, PrefixRef};
+use super::rebuild::LeafPtr;
+use super::{leaf_for, prev_leaf};
+use core::ops::Range;
+
+/// Returns the leaf containing the item at `index` in the tree rooted at
+/// `root`, and the index of the item within the leaf.
+fn find<T, const B: usize>(
+    root: &PrefixRef<T, B, Mutable>,
+    index: usize,
+) -> (LeafPtr<T, B>, usize) {
+    // SAFETY: `root` is borrowed, and the returned `NodeRef` is only used to
+    // get a pointer.
+    let (leaf, index) =
+        leaf_for(unsafe { NodeRef::new(root.as_ptr()) }, index);
+    (leaf.as_ptr(), index)
+}
+
+/// Copies `len` items from `src` to `dest`, where each argument is a leaf and
+/// an index in that leaf.
+///
+/// # Safety
+///
+/// There must be no other references to either leaf.
+unsafe fn copy<T: Copy, const B: usize>(
+    src: (LeafPtr<T, B>, usize),
+    dest: (LeafPtr<T, B>, usize),
+    len: usize,
+) {
+    let ((src, i), (dest, j)) = (src, dest);
+    // SAFETY: Caller guarantees safety.
+    let mut leaf: NodeRef<_, Mutable> = unsafe { NodeRef::new_mutable(dest) };
+    if src == dest {
+        leaf.children_mut().copy_within(i..i + len, j);
+        return;
+    }
+    // SAFETY: Caller guarantees safety, and `src` is a different leaf.
+    let src: NodeRef<_> = unsafe { NodeRef::new(src) };
+    leaf.children_mut()[j..j + len]
+        .copy_from_slice(&src.children()[i..i + len]);
+}
+
+/// Returns the number of items in `leaf`.
+///
+/// # Safety
+///
+/// There must be no mutable references to `leaf`.
+unsafe fn length<T, const B: usize>(leaf: LeafPtr<T, B>) -> usize {
+    // SAFETY: Caller guarantees safety.
+    unsafe { leaf.as_ref() }.length()
+}
+
+/// Copies the items in `src` to the range of the same length starting at
+/// `dest`, in the tree rooted at `root`. The ranges may overlap.
+pub fn copy_within<T: Copy, const B: usize>(
+    root: PrefixRef<T, B, Mutable>,
+    src: Range<usize>,
+    dest: usize,
+) {
+    let mut n = src.len();
+    if n == 0 || src.start == dest {
+        return;
+    }
+    // In the code below, we create only temporary references to the leaves,
+    // which don't overlap with each other, and `root` is borrowed for the
+    // life of each one.
+    if dest < src.start {
+        // Copy from front to back.
+        let mut src = find(&root, src.start);
+        let mut dest = find(&root, dest);
+        while n > 0 {
+            for (leaf, i) in [&mut src, &mut dest] {
+                // SAFETY: See above.
+                if *i == unsafe { length(*leaf) } {
+                    // SAFETY: See above.
+                    *leaf = unsafe { leaf.as_ref() }.next_ptr().unwrap();
+                    *i = 0;
+                }
+            }
+            // SAFETY: See above.
+            let (src_len, dest_len) =
+                unsafe { (length(src.0), length(dest.0)) };
+            let len = n.min(src_len - src.1).min(dest_len - dest.1);
+            // SAFETY: See above.
+            unsafe {
+                copy(src, dest, len);
+            }
+            src.1 += len;
+            dest.1 += len;
+            n -= len;
+        }
+        return;
+    }
+    // Copy from back to front. The indices are one past the last item to
+    // copy.
+    let last = |index| {
+        let (leaf, i) = find(&root, index - 1);
+        (leaf, i + 1)
+    };
+    let mut src = last(src.end);
+    let mut dest = last(dest + n);
+    while n > 0 {
+        for (leaf, i) in [&mut src, &mut dest] {
+            if *i == 0 {
+                // SAFETY: See above.
+                let prev = prev_leaf(unsafe { NodeRef::new(*leaf) });
+                *leaf = prev.unwrap().as_ptr();
+                // SAFETY: See above.
+                *i = unsafe { length(*leaf) };
+            }
+        }
+        let len = n.min(src.1).min(dest.1);
+        src.1 -= len;
+        dest.1 -= len;
+        n -= len;
+        // SAFETY: See above.
+        unsafe {
+            copy(src, dest, len);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 /*
- * Copyright (C) 2021-2023 taylor.fish <contact@taylor.fish>
+ * Copyright (C) 2021-2023, 2026 taylor.fish <contact@taylor.fish>
  *
  * This file is part of btree-vec.
  *
@@ -17,10 +17,21 @@
  * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use super::BTreeVec;
+//! Debugging utilities, available when compiled with
+//! `--cfg btree_vec_debug`.
+//!
+//! The integration tests check [`BTreeVec::validate`] after operations that
+//! change the structure of a tree when this cfg is enabled:
+//!
+//! ```text
+//! RUSTFLAGS="--cfg btree_vec_debug" cargo test
+//! ```
+
 use super::node::{InternalRef, LeafRef, NodeRef, PrefixRef};
 use super::node::{Node, PrefixCast};
+use super::{Allocator, BTreeVec, leaf_for};
 use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
 use core::cell::RefCell;
 use core::fmt::{self, Debug, Display, Formatter};
 use core::ptr::NonNull;
@@ -153,3 +164,84 @@ fn fmt_leaf<T: Debug, const B: usize>(
     }
     Ok(())
 }
+
+impl<T, const B: usize, A: Allocator> BTreeVec<T, B, A> {
+    /// Checks that the tree satisfies all of its invariants, panicking if it
+    /// doesn't.
+    pub fn validate(&self) {
+        let root = if let Some(root) = self.root {
+            // SAFETY: We create `NodeRef`s only according to standard borrow
+            // rules, so no mutable references to data exist.
+            unsafe { NodeRef::new(root) }
+        } else {
+            assert_eq!(self.size, 0);
+            return;
+        };
+        match root.cast() {
+            PrefixCast::Internal(node) => {
+                assert!(node.parent_ref().is_none());
+                assert!(node.length() >= 2, "root has only one child");
+            }
+            PrefixCast::Leaf(node) => assert!(node.parent_ref().is_none()),
+        }
+        let mut leaves = None;
+        let (size, _) = validate_prefix(root, true, &mut leaves);
+        assert_eq!(size, self.size);
+        assert!(leaves.unwrap().into_next().is_err());
+    }
+
+    /// Returns the number of items in each leaf, in order.
+    pub fn leaf_lengths(&self) -> Vec<usize> {
+        let mut lengths = Vec::new();
+        let mut leaf = self.root.map(|root| {
+            // SAFETY: We create `NodeRef`s only according to standard borrow
+            // rules, so no mutable references to data exist.
+            leaf_for(unsafe { NodeRef::new(root) }, 0).0
+        });
+        while let Some(node) = leaf {
+            lengths.push(node.length());
+            leaf = node.into_next().ok();
+        }
+        lengths
+    }
+}
+
+/// Returns the size and height of the subtree. `prev` is the last leaf
+/// visited so far. `exempt` is true if the node is the root, in which case it
+/// may have fewer than the minimum number of children.
+fn validate_prefix<T, const B: usize>(
+    node: PrefixRef<T, B>,
+    exempt: bool,
+    prev: &mut Option<LeafRef<T, B>>,
+) -> (usize, usize) {
+    match node.cast() {
+        PrefixCast::Internal(node) => {
+            assert!(node.length() <= B);
+            assert!(exempt || node.length() >= B / 2, "underfull node");
+            let mut height = None;
+            for i in 0..node.length() {
+                let child = node.child_ref(i);
+                let (size, h) = validate_prefix(child, false, prev);
+                assert_eq!(size, node.sizes[i], "incorrect size");
+                assert_eq!(*height.get_or_insert(h), h, "unbalanced tree");
+                let (parent, index) = match child.cast() {
+                    PrefixCast::Internal(c) => (c.parent_ref(), c.index()),
+                    PrefixCast::Leaf(c) => (c.parent_ref(), c.index()),
+                };
+                assert!(parent.unwrap().as_ptr() == node.as_ptr());
+                assert_eq!(index, i, "incorrect index");
+            }
+            (node.sizes[..node.length()].iter().sum(), height.unwrap() + 1)
+        }
+        PrefixCast::Leaf(node) => {
+            assert!(node.length() <= B);
+            assert!(exempt || node.length() >= B / 2, "underfull leaf");
+            if let Some(prev) = prev {
+                let next = prev.into_next().ok().map(|n| n.as_ptr());
+                assert!(next == Some(node.as_ptr()), "broken leaf chain");
+            }
+            *prev = Some(node);
+            (node.length(), 0)
+        }
+    }
+}
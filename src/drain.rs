@@ -0,0 +1,189 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::node::{Destroy, LeafRef, Mutable, NodeRef, PrefixPtr, PrefixRef};
+use super::rebuild::{LeafPtr, into_leaves};
+use super::{BTreeVec, RawIntoIter, leaf_for};
+use crate::{Allocator, Global, VerifiedAlloc};
+use core::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator};
+
+/// The removed items of a [`Drain`] or [`Splice`], which are contained in a
+/// separate tree.
+///
+/// [`Splice`]: crate::Splice
+pub struct RawDrain<T, const B: usize> {
+    iter: RawIntoIter<T, B>,
+    root: Option<PrefixPtr<T, B>>,
+}
+
+impl<T, const B: usize> RawDrain<T, B> {
+    /// `root` is the root of a tree containing `size` items.
+    pub fn new(root: Option<PrefixRef<T, B, Mutable>>, size: usize) -> Self {
+        let ptr = root.as_ref().map(|root| root.as_ptr());
+        Self {
+            iter: RawIntoIter::new(root, size),
+            root: ptr,
+        }
+    }
+
+    pub fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    pub fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    /// Drops the items that haven't been taken and destroys the tree, which
+    /// must have been allocated by `alloc`. This method has no effect if
+    /// called more than once.
+    pub fn finish(&mut self, alloc: &VerifiedAlloc<impl Allocator>) {
+        // The tree is destroyed even if dropping an item panics.
+        //
+        // SAFETY: `self.iter`'s reference to the tree is taken by
+        // `RawIntoIter::drop_remaining` before `_destroy` is dropped, so there
+        // are no other references to the tree at that point.
+        let _destroy = unsafe { Destroy::new(self.root.take(), alloc) };
+        self.iter.drop_remaining();
+    }
+
+    /// Like [`Self::finish`], but instead of deallocating the leaves of the
+    /// tree, empties them and returns the first leaf of their chain. The
+    /// leaves are no longer part of any tree, and must be reused or destroyed
+    /// with `alloc`. Returns [`None`] if the tree is empty or this method has
+    /// already been called.
+    pub fn finish_into_leaves(
+        &mut self,
+        alloc: &VerifiedAlloc<impl Allocator>,
+    ) -> Option<LeafPtr<T, B>> {
+        // The tree is destroyed if dropping an item panics.
+        //
+        // SAFETY: `self.iter`'s reference to the tree is taken by
+        // `RawIntoIter::drop_remaining`, and the references created below
+        // are temporary, so there are no other references to the tree when
+        // `destroy` could be dropped.
+        let mut destroy = unsafe { Destroy::new(self.root.take(), alloc) };
+        self.iter.drop_remaining();
+        let root = destroy.root?;
+        // SAFETY: See above.
+        let (leaf, _) = leaf_for(unsafe { NodeRef::new_mutable(root) }, 0);
+        let mut next = Some(leaf.as_ptr());
+        while let Some(ptr) = next {
+            // SAFETY: See above.
+            let mut leaf: LeafRef<_, B, Mutable> =
+                unsafe { NodeRef::new_mutable(ptr) };
+            // Only the items in leaves after the current leaf of `self.iter`
+            // remain.
+            leaf.retain(|_| false);
+            next = leaf.next_ptr();
+        }
+        destroy.root = None;
+        // SAFETY: All of the `NodeRef`s used above have been consumed.
+        Some(into_leaves(unsafe { NodeRef::new_mutable(root) }, alloc))
+    }
+}
+
+/// A draining iterator over a range of items in a [`BTreeVec`].
+///
+/// This struct is created by [`BTreeVec::drain`].
+pub struct Drain<'a, T, const B: usize, A: Allocator = Global> {
+    drain: RawDrain<T, B>,
+    vec: &'a mut BTreeVec<T, B, A>,
+}
+
+impl<'a, T, const B: usize, A: Allocator> Drain<'a, T, B, A> {
+    /// `root` is the root of a tree containing `size` items, which must have
+    /// been allocated by `vec`'s allocator.
+    pub(crate) fn new(
+        vec: &'a mut BTreeVec<T, B, A>,
+        root: Option<PrefixRef<T, B, Mutable>>,
+        size: usize,
+    ) -> Self {
+        Self {
+            drain: RawDrain::new(root, size),
+            vec,
+        }
+    }
+}
+
+impl<T, const B: usize, A: Allocator> Iterator for Drain<'_, T, B, A> {
+    type Item = T;
+
+    /// # Time complexity
+    ///
+    /// Constant.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<T, const B: usize, A> DoubleEndedIterator for Drain<'_, T, B, A>
+where
+    A: Allocator,
+{
+    /// # Time complexity
+    ///
+    /// Worst-case Θ(log *k*), where *k* is the number of items in the
+    /// drained range, but iteration over the entire range by repeatedly
+    /// calling this method is only Θ(*k*).
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<T, const B: usize, A: Allocator> FusedIterator for Drain<'_, T, B, A> {}
+
+impl<T, const B: usize, A> ExactSizeIterator for Drain<'_, T, B, A>
+where
+    A: Allocator,
+{
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(Some(lower), upper);
+        lower
+    }
+}
+
+// SAFETY: This type owns the removed items, so it can be `Send` as long as
+// `T` is `Send`.
+unsafe impl<T, const B: usize, A> Send for Drain<'_, T, B, A>
+where
+    T: Send,
+    A: Allocator,
+{
+}
+
+// SAFETY: The only `&self` methods on this type, `size_hint` and `len`, read
+// only the count of remaining items. They don't access any items or the
+// vector.
+unsafe impl<T, const B: usize, A: Allocator> Sync for Drain<'_, T, B, A> {}
+
+impl<T, const B: usize, A: Allocator> Drop for Drain<'_, T, B, A> {
+    fn drop(&mut self) {
+        self.drain.finish(&self.vec.alloc);
+    }
+}
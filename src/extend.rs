@@ -0,0 +1,262 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::BTreeVec;
+use super::node::PrefixRef;
+use super::node::{InternalRef, LeafRef, Mutable, Node, NodeRef, Prefix};
+use super::rebalance::rebalance_path;
+use super::rebuild::{LeafPtr, leaf_mut};
+use crate::{Allocator, VerifiedAlloc};
+
+/// The last leaf of a [`BTreeVec`] to which items are being appended without
+/// searching the tree for each item.
+///
+/// While items are being appended, the sizes that nodes on the path from the
+/// last leaf to the root store for their last child are not kept up to date.
+/// [`Tail::finish`] fixes these sizes and rebalances the path.
+pub struct Tail<T, const B: usize>(Option<LeafRef<T, B, Mutable>>);
+
+impl<T, const B: usize> Tail<T, B> {
+    /// Creates a [`Tail`] for an empty vector.
+    pub fn empty() -> Self {
+        Self(None)
+    }
+
+    /// # Safety
+    ///
+    /// As long as the returned [`Tail`] is used, there must be no other
+    /// references, including [`NodeRef`]s, to any nodes in `vec`. All of the
+    /// methods of the returned [`Tail`] must be given the same `vec`.
+    pub unsafe fn new<A: Allocator>(vec: &mut BTreeVec<T, B, A>) -> Self {
+        // SAFETY: Caller guarantees safety.
+        Self(vec.root.map(|_| unsafe { vec.leaf_for_mut(vec.size) }.0))
+    }
+
+    /// Appends `item`, starting a new leaf if the last leaf has at least
+    /// `fill` items.
+    pub fn push<A: Allocator>(
+        &mut self,
+        vec: &mut BTreeVec<T, B, A>,
+        item: T,
+        fill: usize,
+    ) {
+        self.push_reusing(vec, item, fill, &mut None);
+    }
+
+    /// Like [`Self::push`], but if a new leaf is needed, it is taken from
+    /// the chain of empty leaves starting at `free`, if any, instead of being
+    /// allocated. The leaves in the chain must not be part of any tree, and
+    /// must have been allocated by `vec`'s allocator.
+    pub fn push_reusing<A: Allocator>(
+        &mut self,
+        vec: &mut BTreeVec<T, B, A>,
+        item: T,
+        fill: usize,
+        free: &mut Option<LeafPtr<T, B>>,
+    ) {
+        match &mut self.0 {
+            Some(leaf) if leaf.length() < fill => {
+                let length = leaf.length();
+                leaf.simple_insert(length, item);
+                vec.size += 1;
+            }
+            _ => {
+                let mut new = if let Some(ptr) = *free {
+                    // SAFETY: The free leaves aren't part of any tree, and
+                    // we create only temporary references to them.
+                    let mut leaf = unsafe { leaf_mut(ptr) };
+                    *free = leaf.next_ptr();
+                    leaf.set_next(None);
+                    leaf
+                } else {
+                    LeafRef::alloc(&vec.alloc)
+                };
+                new.simple_insert(0, item);
+                self.push_leaf(vec, new);
+            }
+        }
+    }
+
+    /// Appends `new`, which must not be part of any tree or leaf chain, as
+    /// the new last leaf. `new` must have been allocated by `vec`'s
+    /// allocator.
+    pub fn push_leaf<A: Allocator>(
+        &mut self,
+        vec: &mut BTreeVec<T, B, A>,
+        mut new: LeafRef<T, B, Mutable>,
+    ) {
+        let alloc = &vec.alloc;
+        let ptr = new.as_ptr();
+        let size = new.length();
+        if let Some(mut leaf) = self.0.take() {
+            leaf.link(&mut new);
+            let length = leaf.length();
+            if let Some(root) = attach(leaf, length, new, alloc) {
+                vec.root = Some(root.into_prefix().as_ptr());
+            }
+        } else {
+            vec.root = Some(new.into_prefix().as_ptr());
+        }
+        vec.size += size;
+        // SAFETY: All of the `NodeRef`s used above have been consumed, so
+        // this is the only reference to the new leaf.
+        self.0 = Some(unsafe { NodeRef::new_mutable(ptr) });
+    }
+
+    /// Fixes the sizes on the path from the last leaf to the root and
+    /// rebalances the path. Afterwards, no more items can be appended.
+    pub fn finish<A: Allocator>(&mut self, vec: &mut BTreeVec<T, B, A>) {
+        let leaf = if let Some(leaf) = self.0.take() {
+            leaf
+        } else {
+            return;
+        };
+        let ptr = leaf.as_ptr();
+        let mut size = leaf.length();
+        let mut index = leaf.index();
+        let mut parent = leaf.into_parent().ok();
+        while let Some(mut node) = parent {
+            let length = node.length();
+            node.sizes[index] = size;
+            size = node.sizes[..length].iter().sum();
+            index = node.index();
+            parent = node.into_parent().ok();
+        }
+        // SAFETY: All of the `NodeRef`s used above have been consumed, so
+        // this is the only reference to the leaf.
+        let leaf = unsafe { NodeRef::new_mutable(ptr) };
+        let root = rebalance_path(leaf, &vec.alloc);
+        vec.root = Some(root.as_ptr());
+    }
+}
+
+/// Appends items to the end of a [`BTreeVec`], filling each leaf completely.
+/// The tree is fixed when the [`Extender`] is dropped.
+pub struct Extender<'a, T, const B: usize, A: Allocator> {
+    vec: &'a mut BTreeVec<T, B, A>,
+    tail: Tail<T, B>,
+    /// Empty leaves that are used before new leaves are allocated. Any that
+    /// are left over are destroyed when the [`Extender`] is dropped.
+    free: Option<LeafPtr<T, B>>,
+}
+
+impl<'a, T, const B: usize, A: Allocator> Extender<'a, T, B, A> {
+    pub fn new(vec: &'a mut BTreeVec<T, B, A>) -> Self {
+        Self::reusing(vec, None)
+    }
+
+    /// Creates an [`Extender`] that reuses the chain of empty leaves starting
+    /// at `free`. The leaves must not be part of any tree, and must have been
+    /// allocated by `vec`'s allocator.
+    pub fn reusing(
+        vec: &'a mut BTreeVec<T, B, A>,
+        free: Option<LeafPtr<T, B>>,
+    ) -> Self {
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references. `vec` is
+        // borrowed for the life of the `Extender`, and is the only vector
+        // given to `tail`.
+        let tail = unsafe { Tail::new(vec) };
+        Self {
+            vec,
+            tail,
+            free,
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.tail.push_reusing(self.vec, item, B, &mut self.free);
+    }
+}
+
+impl<T, const B: usize, A: Allocator> Drop for Extender<'_, T, B, A> {
+    fn drop(&mut self) {
+        while let Some(ptr) = self.free {
+            // SAFETY: The free leaves aren't part of any tree, and there are
+            // no other references to them.
+            let mut leaf = unsafe { leaf_mut(ptr) };
+            self.free = leaf.next_ptr();
+            leaf.set_next(None);
+            leaf.destroy(&self.vec.alloc);
+        }
+        self.tail.finish(self.vec);
+    }
+}
+
+/// Removes the items after a given index from a [`BTreeVec`], and adds them
+/// back to the end of the vector when dropped. Items appended to the vector
+/// in the meantime are thus inserted at that index.
+pub struct Reattach<'a, T, const B: usize, A: Allocator> {
+    pub vec: &'a mut BTreeVec<T, B, A>,
+    /// The removed items, and the number of items.
+    tail: Option<(PrefixRef<T, B, Mutable>, usize)>,
+}
+
+impl<'a, T, const B: usize, A: Allocator> Reattach<'a, T, B, A> {
+    pub fn new(vec: &'a mut BTreeVec<T, B, A>, index: usize) -> Self {
+        let size = vec.size - index;
+        let tail = vec.take_range(index..vec.size).map(|root| (root, size));
+        Self {
+            vec,
+            tail,
+        }
+    }
+}
+
+impl<T, const B: usize, A: Allocator> Drop for Reattach<'_, T, B, A> {
+    fn drop(&mut self) {
+        if let Some((root, size)) = self.tail.take() {
+            self.vec.append_tree(root, size);
+        }
+    }
+}
+
+/// Inserts `new` after `node`, which is full and contains `size` items. If
+/// `node`'s parent is also full, a new parent is created for `new`. Returns
+/// the new root, if the root changed.
+fn attach<N, T, const B: usize>(
+    node: NodeRef<N, Mutable>,
+    size: usize,
+    new: NodeRef<N, Mutable>,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> Option<InternalRef<T, B, Mutable>>
+where
+    N: Node<Prefix = Prefix<T, B>>,
+{
+    let index = node.index();
+    let mut parent = match node.into_parent() {
+        Ok(parent) => parent,
+        Err(root) => {
+            let mut parent = InternalRef::alloc(alloc);
+            parent.simple_insert(0, (root.into_prefix(), size));
+            parent.simple_insert(1, (new.into_prefix(), 0));
+            return Some(parent);
+        }
+    };
+    parent.sizes[index] = size;
+    let length = parent.length();
+    if length < B {
+        parent.simple_insert(length, (new.into_prefix(), 0));
+        return None;
+    }
+    let parent_size = parent.sizes.iter().sum();
+    let mut new_parent = InternalRef::alloc(alloc);
+    new_parent.simple_insert(0, (new.into_prefix(), 0));
+    attach(parent, parent_size, new_parent, alloc)
+}
@@ -0,0 +1,193 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::extend::Reattach;
+use super::node::{Compaction, LeafNode, LeafRef, Mutable, NodeRef};
+use super::rebuild::{into_leaves, rebuild};
+use super::{BTreeVec, to_range};
+use crate::{Allocator, Global};
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::{Range, RangeBounds};
+use core::ptr::NonNull;
+
+/// An iterator that removes the items in a range of a [`BTreeVec`] for which
+/// a predicate returns true.
+///
+/// This struct is created by [`BTreeVec::extract_if`]. It yields the removed
+/// items, and repairs the tree when dropped.
+pub struct ExtractIf<'a, T, F, const B: usize, A = Global>
+where
+    F: FnMut(&mut T) -> bool,
+    A: Allocator,
+{
+    /// Holds the items after the range, which are added back after the items
+    /// in the range are.
+    reattach: Reattach<'a, T, B, A>,
+    /// The first leaf in the range. The leaves in the range have been
+    /// detached from the tree.
+    first: Option<NonNull<LeafNode<T, B>>>,
+    /// The leaf currently being visited.
+    leaf: Option<(NonNull<LeafNode<T, B>>, Compaction)>,
+    /// The number of items that haven't been visited.
+    remaining: usize,
+    pred: F,
+}
+
+impl<'a, T, F, const B: usize, A> ExtractIf<'a, T, F, B, A>
+where
+    F: FnMut(&mut T) -> bool,
+    A: Allocator,
+{
+    pub(crate) fn new<R>(
+        vec: &'a mut BTreeVec<T, B, A>,
+        range: R,
+        pred: F,
+    ) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range {
+            start,
+            end,
+        } = to_range(range, vec.len());
+        let reattach = Reattach::new(vec, end);
+        let vec = &mut *reattach.vec;
+        let first = vec
+            .take_range(start..end)
+            .map(|root| into_leaves(root, &vec.alloc));
+        let leaf = first.map(|ptr| {
+            // SAFETY: The leaves aren't part of any tree, and no other
+            // references to them exist.
+            let mut leaf: LeafRef<_, _, Mutable> =
+                unsafe { NodeRef::new_mutable(ptr) };
+            (ptr, leaf.start_compaction())
+        });
+        Self {
+            reattach,
+            first,
+            leaf,
+            remaining: end - start,
+            pred,
+        }
+    }
+}
+
+impl<T, F, const B: usize, A> Iterator for ExtractIf<'_, T, F, B, A>
+where
+    F: FnMut(&mut T) -> bool,
+    A: Allocator,
+{
+    type Item = T;
+
+    /// # Time complexity
+    ///
+    /// Amortized constant, excluding calls to the predicate.
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let (ptr, state) = self.leaf.as_mut()?;
+            // SAFETY: The leaves aren't part of any tree, and we don't create
+            // any other references to them while this one exists.
+            let mut leaf: LeafRef<_, _, Mutable> =
+                unsafe { NodeRef::new_mutable(*ptr) };
+            if state.remaining() == 0 {
+                leaf.end_compaction(state);
+                self.leaf = leaf.next_ptr().map(|ptr| {
+                    // SAFETY: See above.
+                    let mut leaf: LeafRef<_, _, Mutable> =
+                        unsafe { NodeRef::new_mutable(ptr) };
+                    (ptr, leaf.start_compaction())
+                });
+                continue;
+            }
+            let pred = &mut self.pred;
+            let item = leaf.compact_next(state, |item| pred(item));
+            self.remaining -= 1;
+            if item.is_some() {
+                return item;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<T, F, const B: usize, A> FusedIterator for ExtractIf<'_, T, F, B, A>
+where
+    F: FnMut(&mut T) -> bool,
+    A: Allocator,
+{
+}
+
+// SAFETY: This type owns the items in the range and the predicate, so it can
+// be `Send` as long as `T` and `F` are `Send`.
+unsafe impl<T, F, const B: usize, A> Send for ExtractIf<'_, T, F, B, A>
+where
+    T: Send,
+    F: FnMut(&mut T) -> bool + Send,
+    A: Allocator,
+{
+}
+
+// SAFETY: The only `&self` method on this type, `size_hint`, reads only the
+// count of items that haven't been visited. It doesn't access the predicate,
+// any items, or the vector.
+unsafe impl<T, F, const B: usize, A> Sync for ExtractIf<'_, T, F, B, A>
+where
+    F: FnMut(&mut T) -> bool,
+    A: Allocator,
+{
+}
+
+impl<T, F, const B: usize, A> Drop for ExtractIf<'_, T, F, B, A>
+where
+    F: FnMut(&mut T) -> bool,
+    A: Allocator,
+{
+    fn drop(&mut self) {
+        if let Some((ptr, state)) = self.leaf.take() {
+            // SAFETY: The leaves aren't part of any tree, and no other
+            // references to them exist.
+            let mut leaf: LeafRef<_, _, Mutable> =
+                unsafe { NodeRef::new_mutable(ptr) };
+            leaf.end_compaction(&state);
+        }
+        // The remaining items in the range are put in a new tree, to which
+        // the items before the range are joined.
+        let vec = &mut *self.reattach.vec;
+        let size = vec.size;
+        let left = vec.take_range(0..size);
+        rebuild(vec, self.first.take());
+        if let Some(left) = left {
+            let middle = vec.root.replace(left.as_ptr());
+            let middle_size = mem::replace(&mut vec.size, size);
+            if let Some(middle) = middle {
+                // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with
+                // standard borrowing rules, so there are no existing
+                // references.
+                let middle = unsafe { NodeRef::new_mutable(middle) };
+                vec.append_tree(middle, middle_size);
+            }
+        }
+        // The items after the range are added back when `reattach` is
+        // dropped.
+    }
+}
@@ -1,5 +1,5 @@
 /*
- * Copyright (C) 2021-2022 taylor.fish <contact@taylor.fish>
+ * Copyright (C) 2021-2022, 2026 taylor.fish <contact@taylor.fish>
  *
  * This file is part of btree-vec.
  *
@@ -34,9 +34,12 @@ enum InsertionResult<T, const B: usize> {
     Done(PrefixRef<T, B, Mutable>),
 }
 
+/// `root_size` is the size of the tree before the insertion, and `size` is
+/// the number of items inserted.
 fn handle_insertion<N, T, const B: usize>(
     insertion: Insertion<N>,
     root_size: usize,
+    size: usize,
     alloc: &VerifiedAlloc<impl Allocator>,
 ) -> InsertionResult<T, B>
 where
@@ -61,7 +64,7 @@ where
         }
     };
 
-    parent.sizes[index] += 1;
+    parent.sizes[index] += size;
     let (new, new_size) = if let Some(new @ (_, size)) = new {
         parent.sizes[index] -= size;
         new
@@ -122,19 +125,63 @@ pub fn insert<T, const B: usize>(
         item,
         root_size,
     } = insertion;
-    let mut result = handle_insertion(
+    let result = handle_insertion(
         Insertion {
             new: insert_once(&mut node, index, item, alloc),
             node,
         },
         root_size,
+        1,
         alloc,
     );
+    finish_insertion(result, root_size, 1, alloc)
+}
+
+pub struct NodeInsertion<T, const B: usize> {
+    pub node: InternalRef<T, B, Mutable>,
+    pub index: usize,
+    /// The child to insert, and its size.
+    pub child: (PrefixRef<T, B, Mutable>, usize),
+    pub root_size: usize,
+}
+
+/// Inserts an entire subtree as a child of an internal node. The subtree
+/// must have the same height as the node's other children, but it may have
+/// fewer than the minimum number of children.
+pub fn insert_node<T, const B: usize>(
+    insertion: NodeInsertion<T, B>,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> PrefixRef<T, B, Mutable> {
+    let NodeInsertion {
+        mut node,
+        index,
+        child,
+        root_size,
+    } = insertion;
+    let size = child.1;
+    let result = handle_insertion(
+        Insertion {
+            new: insert_once(&mut node, index, child, alloc),
+            node,
+        },
+        root_size,
+        size,
+        alloc,
+    );
+    finish_insertion(result, root_size, size, alloc)
+}
+
+fn finish_insertion<T, const B: usize>(
+    mut result: InsertionResult<T, B>,
+    root_size: usize,
+    size: usize,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> PrefixRef<T, B, Mutable> {
     loop {
         result = match result {
             InsertionResult::Done(root) => return root,
             InsertionResult::Insertion(ins) => {
-                handle_insertion(ins, root_size, alloc)
+                handle_insertion(ins, root_size, size, alloc)
             }
         }
     }
@@ -0,0 +1,135 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::insert::{NodeInsertion, insert_node};
+use super::node::{InternalRef, Mutable, Node, NodeRef};
+use super::node::{PrefixCast, PrefixRef};
+use super::rebalance::{collapse, rebalance, rebalance_path};
+use crate::{Allocator, VerifiedAlloc};
+
+/// Descends at most `depth` levels from `node`, always following the last
+/// child if `last` is true, or the first child otherwise. Returns the node
+/// reached and the number of levels descended.
+pub fn descend<T, const B: usize, R>(
+    mut node: PrefixRef<T, B, R>,
+    last: bool,
+    depth: usize,
+) -> (PrefixRef<T, B, R>, usize) {
+    for i in 0..depth {
+        node = match node.cast() {
+            PrefixCast::Internal(node) => {
+                let index = if last {
+                    node.length() - 1
+                } else {
+                    0
+                };
+                node.into_child(index)
+            }
+            PrefixCast::Leaf(node) => return (node.into_prefix(), i),
+        };
+    }
+    (node, depth)
+}
+
+/// Joins the trees rooted at `left` and `right`, which contain `left_size`
+/// and `right_size` items, respectively. Both trees must be nonempty. Returns
+/// the root of a tree containing the items in `left` followed by the items in
+/// `right`.
+///
+/// The shorter tree is inserted as a child of the node on the adjacent edge
+/// of the taller tree whose children have the same height, and is then
+/// rebalanced.
+pub fn join<T, const B: usize>(
+    left: PrefixRef<T, B, Mutable>,
+    left_size: usize,
+    right: PrefixRef<T, B, Mutable>,
+    right_size: usize,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> PrefixRef<T, B, Mutable> {
+    assert!(left_size > 0);
+    assert!(right_size > 0);
+    let left_ptr = left.as_ptr();
+    let right_ptr = right.as_ptr();
+    let (left_leaf, left_height) = descend(left, true, usize::MAX);
+    let (right_leaf, right_height) = descend(right, false, usize::MAX);
+    if let (PrefixCast::Leaf(mut left_leaf), PrefixCast::Leaf(right_leaf)) =
+        (left_leaf.cast(), right_leaf.cast())
+    {
+        assert!(left_leaf.next_ptr().is_none());
+        left_leaf.set_next(Some(right_leaf.as_ptr()));
+    }
+
+    // SAFETY: All of the `NodeRef`s used above have been consumed, so these
+    // are the only references to the roots.
+    let (left, right) = unsafe {
+        (NodeRef::new_mutable(left_ptr), NodeRef::new_mutable(right_ptr))
+    };
+
+    if left_height == right_height {
+        let mut root = InternalRef::alloc(alloc);
+        root.simple_insert(0, (left, left_size));
+        root.simple_insert(1, (right, right_size));
+        let (root, _) = rebalance(root, 0, B / 2, alloc);
+        let (root, _) = if root.length() == 2 {
+            rebalance(root, 1, B / 2, alloc)
+        } else {
+            (root, 0)
+        };
+        return collapse(root.into_prefix(), alloc);
+    }
+
+    let (graft, insertion) = if left_height > right_height {
+        let depth = left_height - right_height - 1;
+        let node = internal(descend(left, true, depth).0);
+        let index = node.length();
+        (right_ptr, NodeInsertion {
+            node,
+            index,
+            child: (right, right_size),
+            root_size: left_size,
+        })
+    } else {
+        let depth = right_height - left_height - 1;
+        (left_ptr, NodeInsertion {
+            node: internal(descend(right, false, depth).0),
+            index: 0,
+            child: (left, left_size),
+            root_size: right_size,
+        })
+    };
+    insert_node(insertion, alloc);
+
+    // SAFETY: All of the `NodeRef`s used above have been consumed, so this
+    // is the only reference to the grafted tree.
+    let graft: PrefixRef<T, B, Mutable> =
+        unsafe { NodeRef::new_mutable(graft) };
+    match graft.cast() {
+        PrefixCast::Internal(node) => rebalance_path(node, alloc),
+        PrefixCast::Leaf(node) => rebalance_path(node, alloc),
+    }
+}
+
+fn internal<T, const B: usize, R>(
+    node: PrefixRef<T, B, R>,
+) -> InternalRef<T, B, R> {
+    match node.cast() {
+        PrefixCast::Internal(node) => node,
+        PrefixCast::Leaf(_) => unreachable!(),
+    }
+}
@@ -1,5 +1,5 @@
 /*
- * Copyright (C) 2021-2023, 2025 taylor.fish <contact@taylor.fish>
+ * Copyright (C) 2021-2023, 2025-2026 taylor.fish <contact@taylor.fish>
  *
  * This file is part of btree-vec.
  *
@@ -35,9 +35,11 @@
 //!
 //! [cb]: https://www.chiark.greenend.org.uk/~sgtatham/algorithms/cbtree.html
 //!
-//! For now, the vector supports insertions and removals only of single
-//! elements, but bulk operations, including implementations of [`Extend`]
-//! and [`FromIterator`], may be added in the future.
+//! Besides insertions and removals of single elements, the vector implements
+//! [`Extend`] and [`FromIterator`], which append items in bulk without
+//! searching the tree for each item. Large vectors can also be built in
+//! linear time with [`BTreeVecBuilder`], which packs leaves more tightly than
+//! repeated insertions do.
 //!
 //! Example
 //! -------
@@ -97,25 +99,51 @@ use allocator_fallback as allocator;
 mod allocator;
 
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 use allocator::{Allocator, Global};
 use core::cmp::Ordering;
+use core::convert::Infallible;
 use core::fmt::{self, Debug, Formatter};
-use core::iter::{ExactSizeIterator, FusedIterator};
+use core::iter::{self, ExactSizeIterator, FusedIterator};
 use core::marker::PhantomData;
-use core::ops::{Index, IndexMut};
-use core::ptr::NonNull;
+use core::mem::{self, ManuallyDrop};
+use core::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+use core::ptr::{self, NonNull};
 
+mod builder;
+mod concat;
+mod copy;
 #[cfg(btree_vec_debug)]
 pub mod debug;
+mod drain;
+mod extend;
+mod extract_if;
 mod insert;
+mod join;
+mod merge;
 mod node;
+mod partition;
+mod rebalance;
+mod rebuild;
 mod remove;
+mod sort;
+mod splice;
+mod split;
 mod verified_alloc;
 
+pub use builder::BTreeVecBuilder;
+pub use concat::Concat;
+pub use drain::Drain;
+use extend::{Extender, Reattach, Tail};
+pub use extract_if::ExtractIf;
 use insert::{ItemInsertion, insert};
+use join::{descend, join};
 use node::{LeafRef, Mutable, Node, NodeRef};
 use node::{PrefixCast, PrefixPtr, PrefixRef};
+use rebuild::Rebuild;
 use remove::remove;
+pub use splice::Splice;
+use split::split;
 use verified_alloc::VerifiedAlloc;
 
 /// A growable array (vector) implemented as a B+ tree.
@@ -181,6 +209,42 @@ fn leaf_for<T, const B: usize, R>(
     }
 }
 
+/// Returns the leaf before `leaf`, or [`None`] if `leaf` is the first leaf.
+fn prev_leaf<T, const B: usize, R>(
+    leaf: LeafRef<T, B, R>,
+) -> Option<LeafRef<T, B, R>> {
+    let mut index = leaf.index();
+    let mut node = leaf.into_parent().ok()?;
+    let mut depth = 0;
+    while index == 0 {
+        index = node.index();
+        node = node.into_parent().ok()?;
+        depth += 1;
+    }
+    match descend(node.into_child(index - 1), true, depth).0.cast() {
+        PrefixCast::Leaf(leaf) => Some(leaf),
+        PrefixCast::Internal(_) => unreachable!(),
+    }
+}
+
+/// Converts `range` to a [`Range`], panicking if it isn't a valid range of
+/// indices in a vector of length `len`.
+fn to_range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1).expect("range start overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1).expect("range end overflow"),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range start is greater than range end");
+    assert!(end <= len, "range end is out of bounds");
+    start..end
+}
+
 impl<T> BTreeVec<T> {
     /// Creates a new [`BTreeVec`]. Note that this function is implemented
     /// only for the default value of `B`; see [`Self::create`] for an
@@ -224,12 +288,20 @@ impl<T, const B: usize, A: Allocator> BTreeVec<T, B, A> {
         assert!(B >= 3);
         // SAFETY:
         //
-        // * All nodes are allocated by `alloc`, either via the calls to
-        //  `insert` and `LeafRef::alloc` in `Self::insert`. Nodes are
-        //  deallocated in two places: via the call to `remove` in
-        //  `Self::remove`, and via the call to `NodeRef::destroy` in
-        //  `Self::drop`. In both of these cases, `alloc` is provided as the
-        //  allocator with which to deallocate the nodes.
+        // * All nodes in this vector are allocated by `alloc` (`Self.alloc`),
+        //   which is always provided as the allocator when this type's methods
+        //   allocate or deallocate nodes. Nodes are moved from one vector to
+        //   another only by `Self::split_off` and `Self::partition_off`,
+        //   which move them to a vector whose allocator is a clone of
+        //   `alloc`, and by `Self::append_nodes`, `Self::swap_ranges_nodes`,
+        //   and `Self::insert_vec_nodes`, which are implemented only for the
+        //   global allocator.
+        //
+        // * The nodes of a new vector are allocated by another vector's
+        //   `alloc` only in `Self::try_map` (used by `Self::map`), which moves
+        //   that `alloc` to the new vector after destroying the old nodes, and
+        //   in `Self::map_ref` (used by `Clone`), which gives the new vector a
+        //   clone of that `alloc`.
         //
         // * When `alloc` (`Self.alloc`) is dropped, `Self::drop` will have
         //   run, which destroys all nodes. If `alloc`'s memory is reused
@@ -271,6 +343,83 @@ impl<T, const B: usize, A: Allocator> BTreeVec<T, B, A> {
         leaf_for(unsafe { NodeRef::new_mutable(self.root.unwrap()) }, index)
     }
 
+    /// Removes the items in `range` from the tree and returns the root of a
+    /// separate tree containing them, or [`None`] if `range` is empty. The
+    /// returned tree must be destroyed with `self.alloc`.
+    fn take_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Option<PrefixRef<T, B, Mutable>> {
+        let Range {
+            start,
+            end,
+        } = range;
+        if start == end {
+            return None;
+        }
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        let mut root = unsafe { NodeRef::new_mutable(self.root.take()?) };
+        let mut right = None;
+        if end < self.size {
+            let (left, rest) = split(root, end, &self.alloc);
+            root = left;
+            right = Some(rest);
+        }
+        let mut left = None;
+        if start > 0 {
+            let (rest, mid) = split(root, start, &self.alloc);
+            root = mid;
+            left = Some(rest);
+        }
+        let rest = match (left, right) {
+            (Some(left), Some(right)) => {
+                Some(join(left, start, right, self.size - end, &self.alloc))
+            }
+            (left, right) => left.or(right),
+        };
+        self.root = rest.map(|root| root.as_ptr());
+        self.size -= end - start;
+        Some(root)
+    }
+
+    /// Appends the items in the tree rooted at `root`, which contains `size`
+    /// items, to the end of this vector. The tree must have been allocated by
+    /// `self.alloc` (or an allocator that can deallocate its memory).
+    fn append_tree(&mut self, root: PrefixRef<T, B, Mutable>, size: usize) {
+        if size == 0 {
+            root.destroy(&self.alloc);
+            return;
+        }
+        let old = self.root.replace(root.as_ptr());
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        let old = old.map(|old| unsafe { NodeRef::new_mutable(old) });
+        if self.size == 0 {
+            if let Some(old) = old {
+                old.destroy(&self.alloc);
+            }
+        } else {
+            let root = join(old.unwrap(), self.size, root, size, &self.alloc);
+            self.root = Some(root.as_ptr());
+        }
+        self.size += size;
+    }
+
+    /// Calls `f` with the items in each leaf, in order.
+    fn for_each_slice_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut [T]),
+    {
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        let mut leaf = self.root.map(|_| unsafe { self.leaf_for_mut(0) }.0);
+        while let Some(mut node) = leaf {
+            f(node.children_mut());
+            leaf = node.into_next().ok();
+        }
+    }
+
     /// Gets the length of the vector.
     ///
     /// # Time complexity
@@ -304,159 +453,1405 @@ impl<T, const B: usize, A: Allocator> BTreeVec<T, B, A> {
         })
     }
 
-    /// Gets a mutable reference to the item at `index`, or [`None`] if no such
-    /// item exists.
+    /// Gets a mutable reference to the item at `index`, or [`None`] if no such
+    /// item exists.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (index < self.size).then(|| {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with
+            // standard borrowing rules, so there are no existing references.
+            let (leaf, index) = unsafe { self.leaf_for_mut(index) };
+            &mut leaf.into_children_mut()[index]
+        })
+    }
+
+    /// Gets the first item in the vector, or [`None`] if the vector is empty.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Gets a mutable reference to the first item in the vector, or [`None`]
+    /// if the vector is empty.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Gets the last item in the vector, or [`None`] if the vector is empty.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn last(&self) -> Option<&T> {
+        self.size.checked_sub(1).and_then(|s| self.get(s))
+    }
+
+    /// Gets a mutable reference to the last item in the vector, or [`None`] if
+    /// the vector is empty.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.size.checked_sub(1).and_then(move |s| self.get_mut(s))
+    }
+
+    /// Returns the index of the first item for which `pred` returns false,
+    /// assuming that `pred` returns true for all items before that index and
+    /// false for all items after it.
+    ///
+    /// The tree is descended by calling `pred` on the first item of each
+    /// child of a node, and the leaf reached is then binary searched.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log² *n*), with Θ(log *n*) calls to `pred`.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.partition_index(pred)
+    }
+
+    /// Like [`Self::partition_point`], but `pred` may keep the references it
+    /// receives for as long as `self` is borrowed, as with the
+    /// [`Self::binary_search_by`] family.
+    fn partition_index<'a, P>(&'a self, mut pred: P) -> usize
+    where
+        P: FnMut(&'a T) -> bool,
+    {
+        let mut node = if let Some(root) = self.root {
+            // SAFETY: We create `NodeRef`s only according to standard borrow
+            // rules, so no mutable references to data exist.
+            unsafe { NodeRef::new(root) }
+        } else {
+            return 0;
+        };
+        let mut offset = 0;
+        // The number of children of `node` for whose first item `pred` is
+        // already known to return true.
+        let mut known = 0;
+        loop {
+            let internal = match node.cast() {
+                PrefixCast::Internal(node) => node,
+                PrefixCast::Leaf(node) => {
+                    let children = node.into_children();
+                    // Equivalent to `slice::partition_point`, which doesn't
+                    // accept a predicate that keeps its references.
+                    let result = children.binary_search_by(|item| {
+                        if pred(item) {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    });
+                    return offset + result.unwrap_or_else(|i| i);
+                }
+            };
+            let (mut low, mut high) = (known, internal.length());
+            while low < high {
+                let mid = low + (high - low) / 2;
+                let (leaf, _) = leaf_for(internal.child_ref(mid), 0);
+                if pred(&leaf.into_children()[0]) {
+                    low = mid + 1;
+                } else {
+                    high = mid;
+                }
+            }
+            let index = if let Some(index) = low.checked_sub(1) {
+                index
+            } else {
+                return offset;
+            };
+            offset += internal.sizes[..index].iter().sum::<usize>();
+            node = internal.into_child(index);
+            known = 1;
+        }
+    }
+
+    /// Searches the vector, which should be sorted, for an item for which
+    /// `f` returns [`Ordering::Equal`]. `f` should return the ordering of
+    /// the item relative to the target.
+    ///
+    /// As with [`slice::binary_search_by`], this returns [`Ok`] with the
+    /// index of a matching item if there is one, or [`Err`] with the index
+    /// at which a matching item could be inserted while keeping the vector
+    /// sorted. See [`Self::partition_point`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log² *n*), with Θ(log *n*) calls to `f`.
+    pub fn binary_search_by<'a, F>(&'a self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+    {
+        let index = self.partition_index(|item| f(item) == Ordering::Less);
+        match self.get(index) {
+            Some(item) if f(item) == Ordering::Equal => Ok(index),
+            _ => Err(index),
+        }
+    }
+
+    /// Searches the vector, which should be sorted, for `item`. See
+    /// [`Self::binary_search_by`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log² *n*), with Θ(log *n*) comparisons.
+    pub fn binary_search(&self, item: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|other| other.cmp(item))
+    }
+
+    /// Searches the vector, which should be sorted by the key returned by
+    /// `f`, for an item whose key is `key`. See [`Self::binary_search_by`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log² *n*), with Θ(log *n*) calls to `f`.
+    pub fn binary_search_by_key<'a, K, F>(
+        &'a self,
+        key: &K,
+        mut f: F,
+    ) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> K,
+        K: Ord,
+    {
+        self.binary_search_by(|item| f(item).cmp(key))
+    }
+
+    /// Swaps the items at indices `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not less than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.size);
+        assert!(b < self.size);
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        let (leaf, i) = unsafe { self.leaf_for_mut(a) };
+        let ptr = leaf.as_ptr();
+        // SAFETY: The `NodeRef` created above isn't used again.
+        let (mut leaf, j) = unsafe { self.leaf_for_mut(b) };
+        if leaf.as_ptr() == ptr {
+            leaf.children_mut().swap(i, j);
+            return;
+        }
+        // SAFETY: This leaf is different from the other leaf, and there are
+        // no other references to it.
+        let mut other: LeafRef<_, B, Mutable> =
+            unsafe { NodeRef::new_mutable(ptr) };
+        mem::swap(&mut other.children_mut()[i], &mut leaf.children_mut()[j]);
+    }
+
+    /// Inserts `item` at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn insert(&mut self, index: usize, item: T) {
+        assert!(index <= self.size);
+        self.root.get_or_insert_with(|| {
+            LeafRef::alloc(&self.alloc).into_prefix().as_ptr()
+        });
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        let (leaf, index) = unsafe { self.leaf_for_mut(index) };
+        let root = insert(
+            ItemInsertion {
+                node: leaf,
+                index,
+                item,
+                root_size: self.size,
+            },
+            &self.alloc,
+        );
+        self.root = Some(root.as_ptr());
+        self.size += 1;
+    }
+
+    /// Inserts `item` at the end of the vector.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn push(&mut self, item: T) {
+        self.insert(self.size, item);
+    }
+
+    /// Inserts the items in `iter` at `index`, in order.
+    ///
+    /// Rather than searching the tree for each item, this method splits the
+    /// tree at `index`, appends the items to the first part, and joins the
+    /// two parts back together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the number of items inserted.
+    pub fn insert_iter<I>(&mut self, index: usize, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        assert!(index <= self.size);
+        let reattach = Reattach::new(self, index);
+        reattach.vec.extend(iter);
+    }
+
+    /// Inserts clones of the items in `items` at `index`, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the length of `items`.
+    pub fn insert_slice(&mut self, index: usize, items: &[T])
+    where
+        T: Clone,
+    {
+        self.insert_iter(index, items.iter().cloned());
+    }
+
+    /// Inserts all of the items in `other` at `index`, in order.
+    ///
+    /// The items are moved one at a time, as the two vectors may use
+    /// different instances of their allocator. For vectors that use the
+    /// global allocator, [`Self::insert_vec_nodes`] moves `other`'s nodes
+    /// instead, which is faster for large vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*m* + log *n*), where *m* is the length of `other`.
+    pub fn insert_vec(&mut self, index: usize, other: Self) {
+        self.insert_iter(index, other);
+    }
+
+    /// Moves all of the items in `other` to the end of this vector, leaving
+    /// `other` empty.
+    ///
+    /// The items are moved one at a time, as the two vectors may use
+    /// different instances of their allocator. For vectors that use the
+    /// global allocator, [`Self::append_nodes`] moves `other`'s nodes
+    /// instead, which is faster for large vectors.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*m* + log *n*), where *m* is the length of `other`.
+    pub fn append(&mut self, other: &mut Self) {
+        self.extend(other.drain(..));
+    }
+
+    /// Removes and returns the item at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.size);
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with
+        // standard borrowing rules, so there are no existing references.
+        let (leaf, index) = unsafe { self.leaf_for_mut(index) };
+        let (root, item) = remove(leaf, index, &self.alloc);
+        self.root = Some(root.as_ptr());
+        self.size -= 1;
+        item
+    }
+
+    /// Removes and returns the item at `index`, replacing it with the last
+    /// item in the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.size);
+        let last = self.pop().unwrap();
+        if index == self.size {
+            return last;
+        }
+        mem::replace(&mut self[index], last)
+    }
+
+    /// Moves the item at `from` so that it is at index `to`, shifting the
+    /// items between the two positions by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` is not less than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn move_item(&mut self, from: usize, to: usize) {
+        assert!(from < self.size);
+        assert!(to < self.size);
+        if from != to {
+            let item = self.remove(from);
+            self.insert(to, item);
+        }
+    }
+
+    /// Removes and returns the last item in the vector, or [`None`] if the
+    /// vector is empty.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn pop(&mut self) -> Option<T> {
+        self.size.checked_sub(1).map(|s| self.remove(s))
+    }
+
+    /// Shortens the vector to `len` items, dropping the remaining items. If
+    /// `len` is not less than [`self.len()`](Self::len), this method has no
+    /// effect.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the number of items removed.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.size {
+            return;
+        }
+        if len == 0 {
+            self.clear();
+            return;
+        }
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        let root = unsafe { NodeRef::new_mutable(self.root.unwrap()) };
+        let (left, right) = split(root, len, &self.alloc);
+        self.root = Some(left.as_ptr());
+        self.size = len;
+        right.destroy(&self.alloc);
+    }
+
+    /// Removes all items from the vector.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    pub fn clear(&mut self) {
+        self.size = 0;
+        if let Some(root) = self.root.take() {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with
+            // standard borrowing rules, so there are no existing
+            // references.
+            unsafe { NodeRef::new_mutable(root) }.destroy(&self.alloc);
+        }
+    }
+
+    /// Resizes the vector to `new_len` items. If the vector grows, clones of
+    /// `value` are appended; otherwise, the vector is truncated.
+    ///
+    /// The new items are appended in bulk, as with [`Extend`], without
+    /// searching the tree for each item.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the number of items added or removed.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let n = if let Some(n) = new_len.checked_sub(self.size) {
+            n
+        } else {
+            self.truncate(new_len);
+            return;
+        };
+        if n == 0 {
+            return;
+        }
+        let mut extender = Extender::new(self);
+        for _ in 1..n {
+            extender.push(value.clone());
+        }
+        extender.push(value);
+    }
+
+    /// Resizes the vector to `new_len` items. If the vector grows, items
+    /// returned by `f` are appended; otherwise, the vector is truncated.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the number of items added or removed.
+    pub fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        if let Some(n) = new_len.checked_sub(self.size) {
+            self.extend(iter::repeat_with(f).take(n));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Replaces every item in the vector with a clone of `value`.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.for_each_slice_mut(|items| {
+            items.iter_mut().for_each(|item| item.clone_from(&value));
+        });
+    }
+
+    /// Replaces every item in the vector with an item returned by `f`, in
+    /// order.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.for_each_slice_mut(|items| items.fill_with(&mut f));
+    }
+
+    /// Appends clones of the items in `range` to the end of the vector.
+    ///
+    /// The items are cloned directly from their leaves into the end of the
+    /// tree, without being collected first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if the
+    /// end is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the number of items in `range`.
+    pub fn extend_from_within<R>(&mut self, range: R)
+    where
+        T: Clone,
+        R: RangeBounds<usize>,
+    {
+        let range = to_range(range, self.size);
+        if range.is_empty() {
+            return;
+        }
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        let (leaf, mut index) = unsafe { self.leaf_for(range.start) };
+        let mut leaf = leaf.as_ptr();
+        let mut extender = Extender::new(self);
+        for _ in range {
+            // SAFETY: Appending items doesn't move existing items until the
+            // extender is dropped, and no mutable references to this leaf
+            // exist while this reference does. The items after `range` are
+            // never read, so the leaf after the last one in `range` is never
+            // accessed.
+            let mut node = unsafe { leaf.as_ref() };
+            if index == node.length() {
+                leaf = node.next_ptr().unwrap();
+                // SAFETY: See above.
+                node = unsafe { leaf.as_ref() };
+                index = 0;
+            }
+            let item = node.children()[index].clone();
+            index += 1;
+            extender.push(item);
+        }
+    }
+
+    /// Copies the items in `src` to the range of the same length starting at
+    /// `dest`. The ranges may overlap.
+    ///
+    /// The items are copied directly between leaves, from front to back or
+    /// from back to front depending on the direction of the copy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `src` is greater than the end, if the end of
+    /// `src` is greater than [`self.len()`](Self::len), or if `dest` is
+    /// greater than [`self.len()`](Self::len) minus the length of `src`.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the number of items in `src`.
+    pub fn copy_within<R>(&mut self, src: R, dest: usize)
+    where
+        T: Copy,
+        R: RangeBounds<usize>,
+    {
+        let src = to_range(src, self.size);
+        assert!(dest <= self.size - src.len(), "dest is out of bounds");
+        if let Some(root) = self.root {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references.
+            copy::copy_within(
+                unsafe { NodeRef::new_mutable(root) },
+                src,
+                dest,
+            );
+        }
+    }
+
+    /// Rotates the vector in place so that the item at index `mid` becomes
+    /// the first item.
+    ///
+    /// Unlike [`slice::rotate_left`], this method doesn't move any items;
+    /// instead, the tree is split at `mid`, and the two parts are joined in
+    /// the opposite order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.size, "mid is out of bounds");
+        if mid == 0 || mid == self.size {
+            return;
+        }
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        let root = unsafe { NodeRef::new_mutable(self.root.unwrap()) };
+        let (left, right) = split(root, mid, &self.alloc);
+        let root = join(right, self.size - mid, left, mid, &self.alloc);
+        self.root = Some(root.as_ptr());
+    }
+
+    /// Rotates the vector in place so that the last `k` items become the
+    /// first items. See [`Self::rotate_left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.size, "k is out of bounds");
+        self.rotate_left(self.size - k);
+    }
+
+    /// Moves the items in `src` so that they start at index `dest`, shifting
+    /// the items between the two positions to fill the gap.
+    ///
+    /// `dest` is an index in the resulting vector, so after this method
+    /// returns, the moved items occupy `dest..dest + src.len()`. The items
+    /// are moved by splitting them off as a separate tree and joining that
+    /// tree back at the destination, so only the nodes along the boundaries
+    /// are changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `src` is greater than the end, if the end of
+    /// `src` is greater than [`self.len()`](Self::len), or if `dest` is
+    /// greater than [`self.len()`](Self::len) minus the length of `src`.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*).
+    pub fn move_range<R>(&mut self, src: R, dest: usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let src = to_range(src, self.size);
+        let size = src.len();
+        assert!(dest <= self.size - size, "dest is out of bounds");
+        if dest == src.start {
+            return;
+        }
+        if let Some(root) = self.take_range(src) {
+            let reattach = Reattach::new(self, dest);
+            reattach.vec.append_tree(root, size);
+        }
+    }
+
+    /// Swaps the items in `range` with the items in `other_range` in
+    /// `other`. The ranges may have different lengths.
+    ///
+    /// The items are moved one at a time, as the two vectors may use
+    /// different instances of their allocator. For vectors that use the
+    /// global allocator, [`Self::swap_ranges_nodes`] exchanges the ranges'
+    /// nodes instead, which is faster for large ranges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of either range is greater than its end, or if
+    /// the end of either range is greater than the length of its vector.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + *j* + log *n* + log *m*), where *k* and *j* are the numbers
+    /// of items in `range` and `other_range`, and *m* is the length of
+    /// `other`.
+    pub fn swap_ranges<R1, R2>(
+        &mut self,
+        range: R1,
+        other: &mut Self,
+        other_range: R2,
+    ) where
+        R1: RangeBounds<usize>,
+        R2: RangeBounds<usize>,
+    {
+        let range = to_range(range, self.size);
+        let other_range = to_range(other_range, other.size);
+        // The items after each range are added back when these are dropped.
+        let left = Reattach::new(self, range.end);
+        let right = Reattach::new(other, other_range.end);
+        // The items in `range` are first appended after `other_range`, so
+        // the items in `other_range` can then be moved to this vector.
+        right.vec.extend(left.vec.drain(range));
+        left.vec.extend(right.vec.drain(other_range));
+    }
+
+    /// Reverses the order of the items in the vector, in place.
+    ///
+    /// The children of each node and the items in each leaf are reversed, so
+    /// no items are moved between leaves, and no nodes are allocated or
+    /// deallocated.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    pub fn reverse(&mut self) {
+        if let Some(root) = self.root {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references.
+            unsafe { NodeRef::new_mutable(root) }.reverse(&mut None);
+        }
+    }
+
+    /// Removes the items in `range` from the vector, returning them in an
+    /// iterator.
+    ///
+    /// The items are split off into a separate tree, owned by the iterator,
+    /// as soon as this method is called, and the vector is rebalanced only
+    /// along the two boundaries of `range`. Doing this eagerly, rather than
+    /// when the iterator is dropped, keeps the vector valid even if the
+    /// iterator is leaked (e.g., with [`mem::forget`]); the vector will then
+    /// simply be missing the items in `range`. Any items not consumed by the
+    /// iterator are dropped when it is dropped.
+    ///
+    /// [`mem::forget`]: core::mem::forget
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if the
+    /// end is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*). Iteration over all of the removed items is Θ(*k*), where
+    /// *k* is the number of items in `range`.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, B, A>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = to_range(range, self.size);
+        let size = range.len();
+        let root = self.take_range(range);
+        Drain::new(self, root, size)
+    }
+
+    /// Replaces the items in `range` with the items in `replace_with`,
+    /// returning the removed items in an iterator.
+    ///
+    /// As with [`Vec::splice`], the replacement items are inserted when the
+    /// iterator is dropped, and any removed items not consumed by the
+    /// iterator are dropped at that time. The replacement items first fill
+    /// the unused space in the leaf before `range`, and are then placed in
+    /// fully packed leaves, which reuse the leaves that held the removed
+    /// items before any new leaves are allocated. If the iterator is leaked
+    /// (e.g., with [`mem::forget`]), the vector will be missing the items in
+    /// and after `range`.
+    ///
+    /// [`Vec::splice`]: alloc::vec::Vec::splice
+    /// [`mem::forget`]: core::mem::forget
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if the
+    /// end is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*k* + *m* + log *n*), where *k* is the number of items in `range`
+    /// and *m* is the number of replacement items.
+    pub fn splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Splice<'_, I::IntoIter, B, A>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        Splice::new(self, range, replace_with.into_iter())
+    }
+
+    /// Removes the items for which `f` returns false, preserving the order of
+    /// the remaining items.
+    ///
+    /// If `f` panics, the items not yet visited are kept, and the vector is
+    /// left in a valid state.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| f(item));
+    }
+
+    /// Like [`Self::retain`], but `f` receives mutable references to the
+    /// items.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        Rebuild::new(self).for_each_leaf(|leaf| leaf.retain(&mut f));
+    }
+
+    /// Removes the items in `range` for which `filter` returns true,
+    /// returning them in an iterator.
+    ///
+    /// As with [`Vec::extract_if`], the items are visited in order as the
+    /// iterator is advanced, and `filter` may mutate the items it visits. If
+    /// the iterator is dropped before it is exhausted, or if `filter` panics,
+    /// the items not yet visited are kept. The tree is repaired when the
+    /// iterator is dropped. If the iterator is leaked (e.g., with
+    /// [`mem::forget`]), the vector will be missing the items in and after
+    /// `range`.
+    ///
+    /// [`Vec::extract_if`]: alloc::vec::Vec::extract_if
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if the
+    /// end is greater than [`self.len()`](Self::len).
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(log *n*). Iteration over all of the items in `range` and dropping
+    /// the iterator are Θ(*k* + log *n*), where *k* is the number of items
+    /// in `range`.
+    pub fn extract_if<F, R>(
+        &mut self,
+        range: R,
+        filter: F,
+    ) -> ExtractIf<'_, T, F, B, A>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        ExtractIf::new(self, range, filter)
+    }
+
+    /// Sorts the vector, preserving the order of equal items.
+    ///
+    /// This is a merge sort that reuses the vector's leaves: each leaf is
+    /// sorted in place, and runs of leaves are then merged, with the leaves
+    /// emptied by each merge reused for its output. If the comparison
+    /// function panics, all of the items remain in the vector, in an
+    /// unspecified order.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* log *n*).
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the vector with a comparison function, preserving the order of
+    /// equal items. See [`Self::sort`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* log *n*).
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::sort_by(self, true, compare);
+    }
+
+    /// Sorts the vector with a key extraction function, preserving the order
+    /// of equal items. See [`Self::sort`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* log *n*).
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the vector with a key extraction function, preserving the order
+    /// of equal items. `f` is called only once per item.
+    ///
+    /// As with [`slice::sort_by_cached_key`], the keys are collected and
+    /// sorted first, and the items are then moved into place. If `f` or the
+    /// comparison of keys panics, the vector is unchanged.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* log *n*).
+    pub fn sort_by_cached_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        let mut indices: Vec<_> =
+            self.iter().map(f).enumerate().map(|(i, k)| (k, i)).collect();
+        indices.sort_unstable();
+        for i in 0..self.size {
+            let mut index = indices[i].1;
+            while index < i {
+                index = indices[index].1;
+            }
+            indices[i].1 = index;
+            self.swap(i, index);
+        }
+    }
+
+    /// Sorts the vector, but might not preserve the order of equal items.
+    ///
+    /// This uses the same merge as [`Self::sort`], but sorts each leaf with
+    /// an unstable sort. If the comparison function panics, all of the items
+    /// remain in the vector, in an unspecified order.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* log *n*).
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp);
+    }
+
+    /// Sorts the vector with a comparison function, but might not preserve
+    /// the order of equal items. See [`Self::sort_unstable`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* log *n*).
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        sort::sort_by(self, false, compare);
+    }
+
+    /// Sorts the vector with a key extraction function, but might not
+    /// preserve the order of equal items. See [`Self::sort_unstable`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* log *n*).
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Merges this vector and `other`, both of which should be sorted, into
+    /// a single sorted vector. Equal items from this vector come before those
+    /// from `other`.
+    ///
+    /// The items are moved out of both vectors as their leaves are walked,
+    /// and are appended to a vector with fully packed leaves, which uses
+    /// this vector's allocator.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* + *m*), where *m* is the length of `other`.
+    pub fn merge_sorted(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        self.merge_sorted_by(other, T::cmp)
+    }
+
+    /// Merges this vector and `other`, both of which should be sorted
+    /// according to `compare`, into a single sorted vector. See
+    /// [`Self::merge_sorted`].
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n* + *m*), where *m* is the length of `other`.
+    pub fn merge_sorted_by<F>(mut self, other: Self, compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        merge::merge_sorted_by(&mut self, other, compare);
+        self
+    }
+
+    /// Removes consecutive repeated items, keeping the first item of each
+    /// run.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*).
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes all but the first of consecutive items that map to the same
+    /// key.
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*), excluding calls to `key`.
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive items for which
+    /// `same_bucket` returns true.
+    ///
+    /// As with [`Vec::dedup_by`], `same_bucket` is given each item and the
+    /// last item that was kept, in that order, and the item is removed if it
+    /// returns true. The items are compacted within each leaf as they are
+    /// visited, and the tree is rebuilt once at the end. If `same_bucket`
+    /// panics, the items not yet visited are kept.
+    ///
+    /// [`Vec::dedup_by`]: alloc::vec::Vec::dedup_by
+    ///
+    /// # Time complexity
+    ///
+    /// Θ(*n*), excluding calls to `same_bucket`.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut last: Option<NonNull<T>> = None;
+        Rebuild::new(self).for_each_leaf(|leaf| {
+            // SAFETY: `last` points to the last item kept in a previous leaf,
+            // which isn't accessed while this reference exists.
+            let prev = last.map(|item| unsafe { &mut *item.as_ptr() });
+            leaf.dedup_by(prev, &mut same_bucket);
+            if let Some(item) = leaf.children_mut().last_mut() {
+                last = Some(NonNull::from(item));
+            }
+        });
+    }
+
+    /// Gets an iterator that returns references to each item in the vector.
+    ///
+    /// # Time complexity
+    ///
+    /// Iteration over the entire vector is Θ(*n*).
+    pub fn iter(&self) -> Iter<'_, T, B> {
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing mutable references.
+        Iter {
+            leaf: self.root.map(|_| unsafe { self.leaf_for(0) }.0),
+            index: 0,
+            remaining: self.len(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gets an iterator that returns mutable references to each item in the
+    /// vector.
+    ///
+    /// # Time complexity
+    ///
+    /// Iteration over the entire vector is Θ(*n*).
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, B> {
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        IterMut {
+            leaf: self.root.map(|_| unsafe { self.leaf_for_mut(0) }.0),
+            index: 0,
+            remaining: self.len(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a vector from the results of calling `f` with each item in
+    /// this vector, in order.
+    ///
+    /// The new vector has exactly the same tree structure as this one, so no
+    /// rebalancing is needed. It uses this vector's allocator.
     ///
     /// # Time complexity
     ///
-    /// Θ(log *n*).
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        (index < self.size).then(|| {
-            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with
-            // standard borrowing rules, so there are no existing references.
-            let (leaf, index) = unsafe { self.leaf_for_mut(index) };
-            &mut leaf.into_children_mut()[index]
-        })
+    /// Θ(*n*).
+    pub fn map<U, F>(self, mut f: F) -> BTreeVec<U, B, A>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self.try_map(|item| Ok::<_, Infallible>(f(item))) {
+            Ok(vec) => vec,
+            Err(e) => match e {},
+        }
     }
 
-    /// Gets the first item in the vector, or [`None`] if the vector is empty.
+    /// Like [`Self::map`], but stops at the first error returned by `f`, in
+    /// which case the error is returned and the remaining items are dropped.
     ///
     /// # Time complexity
     ///
-    /// Θ(log *n*).
-    pub fn first(&self) -> Option<&T> {
-        self.get(0)
+    /// Θ(*n*).
+    pub fn try_map<U, E, F>(mut self, mut f: F) -> Result<BTreeVec<U, B, A>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let root = self.root.map(|root| {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references.
+            let root = unsafe { NodeRef::new_mutable(root) };
+            root.map_node(&mut None, &self.alloc, &mut |mut leaf, new| {
+                leaf.take_each(|item| {
+                    let length = new.length();
+                    new.simple_insert(length, f(item)?);
+                    Ok(())
+                })
+            })
+        });
+        // If `f` returned an error, the items that weren't moved are dropped
+        // along with `self`.
+        let root = root.transpose()?;
+        let size = mem::take(&mut self.size);
+        if let Some(old) = self.root.take() {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references.
+            unsafe { NodeRef::new_mutable(old) }.destroy(&self.alloc);
+        }
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never used again, and since it contains no nodes,
+        // the allocator is the only field that needs to be dropped. The new
+        // nodes were allocated by this allocator.
+        let alloc = unsafe { ptr::read(&this.alloc) };
+        Ok(BTreeVec {
+            root: root.map(|root| root.as_ptr()),
+            size,
+            alloc,
+            phantom: PhantomData,
+        })
     }
+}
 
-    /// Gets a mutable reference to the first item in the vector, or [`None`]
-    /// if the vector is empty.
+impl<T, const B: usize, A> BTreeVec<T, B, A>
+where
+    A: Allocator + Clone,
+{
+    /// Splits the vector in two at `at`. Items before `at` remain in this
+    /// vector, and the remaining items are returned in a new vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than [`self.len()`](Self::len).
     ///
     /// # Time complexity
     ///
     /// Θ(log *n*).
-    pub fn first_mut(&mut self) -> Option<&mut T> {
-        self.get_mut(0)
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.size);
+        let mut other = Self::create_in(A::clone(&self.alloc));
+        if at == self.size {
+            return other;
+        }
+        if at == 0 {
+            mem::swap(&mut self.root, &mut other.root);
+            mem::swap(&mut self.size, &mut other.size);
+            return other;
+        }
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references. The nodes
+        // moved to `other` can be deallocated by `other.alloc`, as it is a
+        // clone of `self.alloc`.
+        let root = unsafe { NodeRef::new_mutable(self.root.unwrap()) };
+        let (left, right) = split(root, at, &self.alloc);
+        self.root = Some(left.as_ptr());
+        other.root = Some(right.as_ptr());
+        other.size = self.size - at;
+        self.size = at;
+        other
     }
 
-    /// Gets the last item in the vector, or [`None`] if the vector is empty.
+    /// Moves the items for which `pred` returns false to a new vector, which
+    /// is returned, and keeps the items for which it returns true. The order
+    /// of the items is preserved in both vectors.
+    ///
+    /// The leaves of this vector are reused for both vectors, whose leaves
+    /// are fully packed. The new vector uses a clone of this vector's
+    /// allocator.
+    ///
+    /// If `pred` panics, all of the items remain in this vector, but in an
+    /// unspecified order.
     ///
     /// # Time complexity
     ///
-    /// Θ(log *n*).
-    pub fn last(&self) -> Option<&T> {
-        self.size.checked_sub(1).and_then(|s| self.get(s))
+    /// Θ(*n*).
+    pub fn partition_off<F>(&mut self, pred: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        partition::partition_off(self, pred)
     }
 
-    /// Gets a mutable reference to the last item in the vector, or [`None`] if
-    /// the vector is empty.
+    /// Splits this vector into the items for which `pred` returns true and
+    /// the items for which it returns false, in that order. See
+    /// [`Self::partition_off`].
     ///
     /// # Time complexity
     ///
-    /// Θ(log *n*).
-    pub fn last_mut(&mut self) -> Option<&mut T> {
-        self.size.checked_sub(1).and_then(move |s| self.get_mut(s))
+    /// Θ(*n*).
+    pub fn partition_into<F>(mut self, pred: F) -> (Self, Self)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let other = self.partition_off(pred);
+        (self, other)
     }
 
-    /// Inserts `item` at `index`.
+    /// Creates a vector from the results of calling `f` with a reference to
+    /// each item in this vector, in order.
     ///
-    /// # Panics
-    ///
-    /// Panics if `index` is greater than [`self.len()`](Self::len).
+    /// The new vector has exactly the same tree structure as this one, so no
+    /// rebalancing is needed. It uses a clone of this vector's allocator.
     ///
     /// # Time complexity
     ///
-    /// Θ(log *n*).
-    pub fn insert(&mut self, index: usize, item: T) {
-        assert!(index <= self.size);
-        self.root.get_or_insert_with(|| {
-            LeafRef::alloc(&self.alloc).into_prefix().as_ptr()
+    /// Θ(*n*).
+    pub fn map_ref<U, F>(&self, mut f: F) -> BTreeVec<U, B, A>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let root = self.root.map(|root| {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing mutable references.
+            let root = unsafe { NodeRef::new(root) };
+            let result =
+                root.map_node(&mut None, &self.alloc, &mut |leaf, new| {
+                    for item in leaf.children() {
+                        let length = new.length();
+                        new.simple_insert(length, f(item));
+                    }
+                    Ok::<_, Infallible>(())
+                });
+            match result {
+                Ok(root) => root.as_ptr(),
+                Err(e) => match e {},
+            }
         });
-        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
-        // borrowing rules, so there are no existing references.
-        let (leaf, index) = unsafe { self.leaf_for_mut(index) };
-        let root = insert(
-            ItemInsertion {
-                node: leaf,
-                index,
-                item,
-                root_size: self.size,
-            },
-            &self.alloc,
-        );
-        self.root = Some(root.as_ptr());
-        self.size += 1;
+        BTreeVec {
+            root,
+            size: self.size,
+            alloc: self.alloc.clone(),
+            phantom: PhantomData,
+        }
     }
+}
 
-    /// Inserts `item` at the end of the vector.
+impl<T, const B: usize> BTreeVec<T, B> {
+    /// Moves all of the items in `other` to the end of this vector, leaving
+    /// `other` empty, like [`Self::append`].
+    ///
+    /// No items are moved; instead, the tree in `other` is joined onto this
+    /// vector's tree. This is possible only for vectors that use the global
+    /// allocator, as nodes allocated by one instance of a custom allocator
+    /// can't necessarily be deallocated by another.
     ///
     /// # Time complexity
     ///
-    /// Θ(log *n*).
-    pub fn push(&mut self, item: T) {
-        self.insert(self.size, item);
+    /// Θ(log *n* + log *m*), where *m* is the length of `other`.
+    pub fn append_nodes(&mut self, other: &mut Self) {
+        let size = mem::take(&mut other.size);
+        if let Some(root) = other.root.take() {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references. Both
+            // vectors use the global allocator, so `other`'s nodes can be
+            // deallocated by `self.alloc`.
+            self.append_tree(unsafe { NodeRef::new_mutable(root) }, size);
+        }
     }
 
-    /// Removes and returns the item at `index`.
+    /// Inserts all of the items in `other` at `index`, like
+    /// [`Self::insert_vec`].
+    ///
+    /// No items are moved; instead, this vector is split at `index`, and the
+    /// tree in `other` is joined between the two parts. Like
+    /// [`Self::append_nodes`], only available with the global allocator.
     ///
     /// # Panics
     ///
-    /// Panics if `index` is not less than [`self.len()`](Self::len).
+    /// Panics if `index` is greater than [`self.len()`](Self::len).
     ///
     /// # Time complexity
     ///
-    /// Θ(log *n*).
-    pub fn remove(&mut self, index: usize) -> T {
-        assert!(index < self.size);
-        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with
-        // standard borrowing rules, so there are no existing references.
-        let (leaf, index) = unsafe { self.leaf_for_mut(index) };
-        let (root, item) = remove(leaf, index, &self.alloc);
-        self.root = Some(root.as_ptr());
-        self.size -= 1;
-        item
+    /// Θ(log *n* + log *m*), where *m* is the length of `other`.
+    pub fn insert_vec_nodes(&mut self, index: usize, mut other: Self) {
+        assert!(index <= self.size);
+        let size = mem::take(&mut other.size);
+        if let Some(root) = other.root.take() {
+            let reattach = Reattach::new(self, index);
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references. Both
+            // vectors use the global allocator, so `other`'s nodes can be
+            // deallocated by `self.alloc`.
+            let root = unsafe { NodeRef::new_mutable(root) };
+            reattach.vec.append_tree(root, size);
+        }
     }
 
-    /// Removes and returns the last item in the vector, or [`None`] if the
-    /// vector is empty.
+    /// Swaps the items in `range` with the items in `other_range` in
+    /// `other`, like [`Self::swap_ranges`].
     ///
-    /// # Time complexity
+    /// The items are not moved individually; instead, each range is split
+    /// off as a separate tree, and the trees are joined into the other
+    /// vector. Like [`Self::append_nodes`], only available with the global
+    /// allocator.
     ///
-    /// Θ(log *n*).
-    pub fn pop(&mut self) -> Option<T> {
-        self.size.checked_sub(1).map(|s| self.remove(s))
-    }
-
-    /// Gets an iterator that returns references to each item in the vector.
+    /// # Panics
+    ///
+    /// Panics if the start of either range is greater than its end, or if
+    /// the end of either range is greater than the length of its vector.
     ///
     /// # Time complexity
     ///
-    /// Iteration over the entire vector is Θ(*n*).
-    pub fn iter(&self) -> Iter<'_, T, B> {
-        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
-        // borrowing rules, so there are no existing mutable references.
-        Iter {
-            leaf: self.root.map(|_| unsafe { self.leaf_for(0) }.0),
-            index: 0,
-            remaining: self.len(),
-            phantom: PhantomData,
+    /// Θ(log *n* + log *m*), where *m* is the length of `other`.
+    pub fn swap_ranges_nodes<R1, R2>(
+        &mut self,
+        range: R1,
+        other: &mut Self,
+        other_range: R2,
+    ) where
+        R1: RangeBounds<usize>,
+        R2: RangeBounds<usize>,
+    {
+        let range = to_range(range, self.size);
+        let other_range = to_range(other_range, other.size);
+        let (size, other_size) = (range.len(), other_range.len());
+        // The items after each range are added back when these are dropped.
+        let left = Reattach::new(self, range.end);
+        let right = Reattach::new(other, other_range.end);
+        let root = left.vec.take_range(range);
+        let other_root = right.vec.take_range(other_range);
+        // Both vectors use the global allocator, so their nodes can be
+        // deallocated by either vector's allocator.
+        if let Some(root) = other_root {
+            left.vec.append_tree(root, other_size);
+        }
+        if let Some(root) = root {
+            right.vec.append_tree(root, size);
         }
     }
+}
 
-    /// Gets an iterator that returns mutable references to each item in the
-    /// vector.
+impl<T, const B: usize, A> BTreeVec<T, B, A>
+where
+    A: Allocator + Default,
+{
+    /// Creates a vector containing the items in `iter`, whose length must be
+    /// known in advance.
+    ///
+    /// Unlike [`Self::from_iter`], this function distributes the items evenly
+    /// among the leaves of the tree, all of which are as full as possible.
+    /// See also [`BTreeVecBuilder`].
+    ///
+    /// If `iter` doesn't yield the number of items its [`len`] reports, the
+    /// resulting vector is still valid, but may not be as compact.
+    ///
+    /// [`len`]: ExactSizeIterator::len
     ///
     /// # Time complexity
     ///
-    /// Iteration over the entire vector is Θ(*n*).
-    pub fn iter_mut(&mut self) -> IterMut<'_, T, B> {
-        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
-        // borrowing rules, so there are no existing references.
-        IterMut {
-            leaf: self.root.map(|_| unsafe { self.leaf_for_mut(0) }.0),
-            index: 0,
-            remaining: self.len(),
-            phantom: PhantomData,
+    /// Θ(*n*).
+    pub fn from_exact_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let len = iter.len();
+        let leaves = ((len + B - 1) / B).max(1);
+        let fill = len / leaves;
+        // The first `(len % leaves)` leaves hold one extra item.
+        let larger = (len % leaves) * (fill + 1);
+        let mut vec = Self::default();
+        let mut tail = Tail::empty();
+        for (i, item) in iter.enumerate() {
+            // A new leaf is started only when the last leaf holds at least
+            // the given number of items, so because the number of items per
+            // leaf never increases, each leaf receives the right amount.
+            let fill = if i < larger {
+                fill + 1
+            } else if i < len {
+                fill
+            } else {
+                B
+            };
+            tail.push(&mut vec, item, fill);
         }
+        tail.finish(&mut vec);
+        vec
     }
 }
 
@@ -518,20 +1913,44 @@ where
     A: Clone + Allocator,
 {
     fn clone(&self) -> Self {
-        let root = self.root.map(|root| {
-            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
-            // borrowing rules, so there are no existing references.
-            unsafe { NodeRef::new(root) }
-                .clone_node(None, &self.alloc)
-                .0
-                .as_ptr()
-        });
-        Self {
-            root,
-            size: self.size,
-            alloc: self.alloc.clone(),
-            phantom: self.phantom,
-        }
+        self.map_ref(T::clone)
+    }
+}
+
+impl<T, const B: usize, A: Allocator> Extend<T> for BTreeVec<T, B, A> {
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the number of items added.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut extender = Extender::new(self);
+        iter.into_iter().for_each(|item| extender.push(item));
+    }
+}
+
+impl<'a, T, const B: usize, A> Extend<&'a T> for BTreeVec<T, B, A>
+where
+    T: Copy + 'a,
+    A: Allocator,
+{
+    /// # Time complexity
+    ///
+    /// Θ(*k* + log *n*), where *k* is the number of items added.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, const B: usize, A> FromIterator<T> for BTreeVec<T, B, A>
+where
+    A: Allocator + Default,
+{
+    /// # Time complexity
+    ///
+    /// Θ(*k*), where *k* is the number of items in the iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::default();
+        vec.extend(iter);
+        vec
     }
 }
 
@@ -767,27 +2186,63 @@ where
     }
 }
 
-/// An owning iterator over the items in a [`BTreeVec`].
-pub struct IntoIter<T, const B: usize, A: Allocator = Global> {
+/// Moves items out of a chain of leaves. Leaves are emptied as they are
+/// reached, so the tree containing them can be destroyed afterward without
+/// dropping any item twice, as long as [`Self::drop_remaining`] is called
+/// first.
+struct RawIntoIter<T, const B: usize> {
     leaf: Option<LeafRef<T, B, Mutable>>,
     length: usize,
     index: usize,
+    /// The last leaf in the chain, if it isn't `leaf`. Items are taken from
+    /// the end of this leaf by [`Self::next_back`], which keeps its length up
+    /// to date.
+    back: Option<LeafRef<T, B, Mutable>>,
     remaining: usize,
-    _tree: BTreeVec<T, B, A>,
 }
 
-impl<T, const B: usize, A: Allocator> Iterator for IntoIter<T, B, A> {
-    type Item = T;
+impl<T, const B: usize> RawIntoIter<T, B> {
+    /// `root` is the root of a tree containing `remaining` items.
+    fn new(root: Option<PrefixRef<T, B, Mutable>>, remaining: usize) -> Self {
+        let (leaf, back) = if let Some(root) = root {
+            let ptr = root.as_ptr();
+            let (leaf, _) = leaf_for(root, 0);
+            // SAFETY: `last` is kept only if it isn't `leaf`, and neither is
+            // used to access the other's items.
+            let root = unsafe { NodeRef::new_mutable(ptr) };
+            let (last, _) = leaf_for(root, remaining);
+            let back = if last.as_ptr() == leaf.as_ptr() {
+                None
+            } else {
+                Some(last)
+            };
+            (Some(leaf), back)
+        } else {
+            (None, None)
+        };
+        Self {
+            length: leaf.as_ref().map_or(0, |leaf| leaf.length()),
+            leaf,
+            index: 0,
+            back,
+            remaining,
+        }
+    }
 
-    /// # Time complexity
-    ///
-    /// Worst-case Θ(log *n*), but iteration over the entire vector by
-    /// repeatedly calling this method is only Θ(*n*).
-    fn next(&mut self) -> Option<Self::Item> {
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
         let mut leaf = self.leaf.as_mut()?;
         if self.index == self.length {
             self.leaf = self.leaf.take().unwrap().into_next().ok();
             leaf = self.leaf.as_mut()?;
+            let back = self.back.as_ref().map(|back| back.as_ptr());
+            if back == Some(leaf.as_ptr()) {
+                // Items have been taken only from the end of this leaf, and
+                // its length reflects that.
+                self.back = None;
+            }
             self.index = 0;
             self.length = leaf.length();
             leaf.set_zero_length();
@@ -799,9 +2254,73 @@ impl<T, const B: usize, A: Allocator> Iterator for IntoIter<T, B, A> {
         Some(unsafe { leaf.take_raw_child(index).assume_init() })
     }
 
+    fn next_back(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        while let Some(back) = &mut self.back {
+            if let Some(index) = back.length().checked_sub(1) {
+                // SAFETY: Items at `0..length` are initialized, and
+                // `take_raw_child` reduces the length.
+                return Some(unsafe {
+                    back.take_raw_child(index).assume_init()
+                });
+            }
+            let leaf = self.leaf.as_ref().map(|leaf| leaf.as_ptr());
+            self.back = prev_leaf(self.back.take().unwrap())
+                .filter(|back| Some(back.as_ptr()) != leaf);
+        }
+        // All of the remaining items are in the current leaf.
+        let leaf = self.leaf.as_mut()?;
+        self.length -= 1;
+        // SAFETY: We haven't taken the item at `self.length` yet.
+        Some(unsafe { leaf.take_raw_child(self.length).assume_init() })
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.remaining, Some(self.remaining))
     }
+
+    /// Drops the items in the current leaf that haven't been taken. Items in
+    /// later leaves are left in place. Afterward, this iterator no longer
+    /// refers to any leaves.
+    fn drop_remaining(&mut self) {
+        self.back = None;
+        let mut leaf = if let Some(leaf) = self.leaf.take() {
+            leaf
+        } else {
+            return;
+        };
+        for i in self.index..self.length {
+            // SAFETY: We haven't taken the item at `index` yet.
+            unsafe {
+                leaf.take_raw_child(i).assume_init();
+            }
+        }
+    }
+}
+
+/// An owning iterator over the items in a [`BTreeVec`].
+pub struct IntoIter<T, const B: usize, A: Allocator = Global> {
+    iter: RawIntoIter<T, B>,
+    _tree: BTreeVec<T, B, A>,
+}
+
+impl<T, const B: usize, A: Allocator> Iterator for IntoIter<T, B, A> {
+    type Item = T;
+
+    /// # Time complexity
+    ///
+    /// Worst-case Θ(log *n*), but iteration over the entire vector by
+    /// repeatedly calling this method is only Θ(*n*).
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
 impl<T, const B: usize, A: Allocator> FusedIterator for IntoIter<T, B, A> {}
@@ -828,17 +2347,7 @@ unsafe impl<T, const B: usize, A: Allocator> Sync for IntoIter<T, B, A> {}
 
 impl<T, const B: usize, A: Allocator> Drop for IntoIter<T, B, A> {
     fn drop(&mut self) {
-        let mut leaf = if let Some(leaf) = self.leaf.take() {
-            leaf
-        } else {
-            return;
-        };
-        for i in self.index..self.length {
-            // SAFETY: We haven't taken the item at `index` yet.
-            unsafe {
-                leaf.take_raw_child(i).assume_init();
-            }
-        }
+        self.iter.drop_remaining();
     }
 }
 
@@ -846,16 +2355,13 @@ impl<T, const B: usize, A: Allocator> IntoIterator for BTreeVec<T, B, A> {
     type Item = T;
     type IntoIter = IntoIter<T, B, A>;
 
-    fn into_iter(mut self) -> Self::IntoIter {
+    fn into_iter(self) -> Self::IntoIter {
         // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
         // borrowing rules, so because we own the `BTreeVec`, there are no
         // existing references.
-        let leaf = self.root.map(|_| unsafe { self.leaf_for_mut(0) }.0);
+        let root = self.root.map(|root| unsafe { NodeRef::new_mutable(root) });
         IntoIter {
-            index: 0,
-            length: leaf.as_ref().map_or(0, |leaf| leaf.length()),
-            leaf,
-            remaining: self.len(),
+            iter: RawIntoIter::new(root, self.len()),
             _tree: self,
         }
     }
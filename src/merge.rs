@@ -0,0 +1,86 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Merging of two sorted vectors.
+
+use super::BTreeVec;
+use super::drain::RawDrain;
+use super::extend::Tail;
+use super::node::NodeRef;
+use crate::Allocator;
+use core::cmp::Ordering;
+use core::mem;
+
+/// Appends merged items to a vector whose original items have been moved to
+/// a separate tree. When dropped, the vector's tree is fixed, and the items
+/// remaining in the separate tree are dropped, even if the comparison
+/// function panics.
+struct Merge<'a, T, const B: usize, A: Allocator> {
+    vec: &'a mut BTreeVec<T, B, A>,
+    tail: Tail<T, B>,
+    /// The original items of `vec`.
+    left: RawDrain<T, B>,
+}
+
+impl<T, const B: usize, A: Allocator> Drop for Merge<'_, T, B, A> {
+    fn drop(&mut self) {
+        self.tail.finish(self.vec);
+        self.left.finish(&self.vec.alloc);
+    }
+}
+
+/// Merges the items in `vec` and `other`, which should both be sorted
+/// according to `compare`, into `vec`. Equal items from `vec` come first.
+pub fn merge_sorted_by<T, const B: usize, A, F>(
+    vec: &mut BTreeVec<T, B, A>,
+    other: BTreeVec<T, B, A>,
+    mut compare: F,
+) where
+    A: Allocator,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let size = mem::take(&mut vec.size);
+    let root = vec.root.take().map(|root| {
+        // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+        // borrowing rules, so there are no existing references.
+        unsafe { NodeRef::new_mutable(root) }
+    });
+    let mut merge = Merge {
+        vec,
+        tail: Tail::empty(),
+        left: RawDrain::new(root, size),
+    };
+    let mut right = other.into_iter();
+    let mut l = merge.left.next();
+    let mut r = right.next();
+    loop {
+        let take_right = match (&l, &r) {
+            (Some(l), Some(r)) => compare(r, l) == Ordering::Less,
+            (Some(_), None) => false,
+            (None, Some(_)) => true,
+            (None, None) => break,
+        };
+        let item = if take_right {
+            mem::replace(&mut r, right.next())
+        } else {
+            mem::replace(&mut l, merge.left.next())
+        };
+        merge.tail.push(merge.vec, item.unwrap(), B);
+    }
+}
@@ -1,5 +1,5 @@
 /*
- * Copyright (C) 2021-2022, 2025 taylor.fish <contact@taylor.fish>
+ * Copyright (C) 2021-2022, 2025-2026 taylor.fish <contact@taylor.fish>
  *
  * This file is part of btree-vec.
  *
@@ -17,7 +17,7 @@
  * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use super::{InternalRef, Mutable, NodeRef, PrefixRef};
+use super::{InternalRef, Mutable, NodeRef, PrefixCast, PrefixRef};
 use super::{LeafNode, Node, NodeKind, Prefix, PrefixPtr, SplitStrategy};
 use crate::{Allocator, VerifiedAlloc};
 use core::marker::PhantomData as Pd;
@@ -152,6 +152,14 @@ impl<T, const B: usize> InternalNode<T, B> {
         unsafe { self.child_ptr(i).unwrap().as_mut() }
     }
 
+    /// Gets the number of children of child `i`.
+    pub fn child_length(&self, i: usize) -> usize {
+        match NodeRef::<_>(self.child_ptr(i).unwrap(), Pd).cast() {
+            PrefixCast::Internal(node) => node.length(),
+            PrefixCast::Leaf(node) => node.length(),
+        }
+    }
+
     pub fn size(&self) -> usize {
         self.sizes.iter().sum()
     }
@@ -230,29 +238,191 @@ impl<T, const B: usize, R> NodeRef<InternalNode<T, B>, R> {
     }
 }
 
+impl<T, const B: usize> NodeRef<InternalNode<T, B>, Mutable> {
+    /// Moves the first `n` children of `next` to the end of this node.
+    /// Returns the total size of the moved children.
+    pub fn take_front(
+        &mut self,
+        next: &mut InternalNode<T, B>,
+        n: usize,
+    ) -> usize {
+        let length = self.length;
+        assert!(length + n <= B);
+        assert!(n <= next.length);
+        let ptr = self.0;
+        let size = next.sizes[..n].iter().sum();
+        self.sizes[length..][..n].copy_from_slice(&next.sizes[..n]);
+        next.children[..n]
+            .iter_mut()
+            .map(|c| c.take().unwrap())
+            .zip(&mut self.children[length..])
+            .enumerate()
+            .for_each(|(i, (mut next_child, self_child))| {
+                // SAFETY: We have the only reference to `next_child`, and this
+                // type's invariants guarantee its validity.
+                let prefix = unsafe { next_child.as_mut() };
+                prefix.parent.set(Some(ptr));
+                prefix.index = length + i;
+                *self_child = Some(next_child);
+            });
+        let next_length = next.length;
+        next.children[..next_length].rotate_left(n);
+        next.sizes[..next_length].rotate_left(n);
+        next.sizes[next_length - n..next_length].fill(0);
+        next.length -= n;
+        self.length += n;
+        for i in 0..next.length {
+            next.child_mut(i).index = i;
+        }
+        size
+    }
+
+    /// Moves the last `n` children of `prev` to the start of this node.
+    /// Returns the total size of the moved children.
+    pub fn take_back(
+        &mut self,
+        prev: &mut InternalNode<T, B>,
+        n: usize,
+    ) -> usize {
+        let length = self.length;
+        assert!(length + n <= B);
+        assert!(n <= prev.length);
+        let ptr = self.0;
+        let start = prev.length - n;
+        let size = prev.sizes[start..prev.length].iter().sum();
+        self.children[..length + n].rotate_right(n);
+        self.sizes[..length + n].rotate_right(n);
+        self.sizes[..n].copy_from_slice(&prev.sizes[start..prev.length]);
+        prev.sizes[start..prev.length].fill(0);
+        prev.children[start..prev.length]
+            .iter_mut()
+            .map(|c| c.take().unwrap())
+            .zip(&mut self.children[..n])
+            .for_each(|(mut prev_child, self_child)| {
+                // SAFETY: We have the only reference to `prev_child`, and this
+                // type's invariants guarantee its validity.
+                let prefix = unsafe { prev_child.as_mut() };
+                prefix.parent.set(Some(ptr));
+                *self_child = Some(prev_child);
+            });
+        prev.length -= n;
+        self.length += n;
+        for i in 0..self.length {
+            self.child_mut(i).index = i;
+        }
+        size
+    }
+
+    /// Moves the first `n` items (or children) of child `i + 1` to the end of
+    /// child `i`.
+    pub fn shift_left(&mut self, i: usize, n: usize) {
+        let right = self.child_ptr(i + 1).unwrap();
+        let size = match self.child_mut_ref(i).cast() {
+            PrefixCast::Internal(mut left) => {
+                // SAFETY: `right` is a different node from `left`, and we
+                // have the only reference to it.
+                left.take_front(unsafe { right.cast().as_mut() }, n)
+            }
+            PrefixCast::Leaf(mut left) => {
+                // SAFETY: See above.
+                left.take_front(unsafe { right.cast().as_mut() }, n);
+                n
+            }
+        };
+        self.sizes[i] += size;
+        self.sizes[i + 1] -= size;
+    }
+
+    /// Moves the last `n` items (or children) of child `i` to the start of
+    /// child `i + 1`.
+    pub fn shift_right(&mut self, i: usize, n: usize) {
+        let left = self.child_ptr(i).unwrap();
+        let size = match self.child_mut_ref(i + 1).cast() {
+            PrefixCast::Internal(mut right) => {
+                // SAFETY: `left` is a different node from `right`, and we
+                // have the only reference to it.
+                right.take_back(unsafe { left.cast().as_mut() }, n)
+            }
+            PrefixCast::Leaf(mut right) => {
+                // SAFETY: See above.
+                right.take_back(unsafe { left.cast().as_mut() }, n);
+                n
+            }
+        };
+        self.sizes[i] -= size;
+        self.sizes[i + 1] += size;
+    }
+
+    /// Moves all of the items (or children) of child `i + 1` to the end of
+    /// child `i`, and then removes child `i + 1`, which is returned.
+    pub fn merge_children(&mut self, i: usize) -> PrefixRef<T, B, Mutable> {
+        let right = self.child_ptr(i + 1).unwrap();
+        match self.child_mut_ref(i).cast() {
+            PrefixCast::Internal(mut left) => {
+                // SAFETY: `right` is a different node from `left`, and we
+                // have the only reference to it.
+                let right =
+                    unsafe { right.cast::<InternalNode<T, B>>().as_mut() };
+                left.take_front(right, right.length);
+            }
+            PrefixCast::Leaf(mut left) => {
+                // SAFETY: See above.
+                left.absorb(unsafe { right.cast().as_mut() });
+            }
+        }
+        self.sizes[i] += mem::take(&mut self.sizes[i + 1]);
+        self.simple_remove(i + 1).0
+    }
+
+    /// Reverses the order of the items in the subtree rooted at this node.
+    /// See [`PrefixRef::reverse`].
+    pub fn reverse(&mut self, prev: &mut Option<NonNull<LeafNode<T, B>>>) {
+        let length = self.length;
+        for i in 0..length {
+            self.child_mut_ref(i).reverse(prev);
+        }
+        self.children[..length].reverse();
+        self.sizes[..length].reverse();
+        for i in 0..length {
+            self.child_mut(i).index = i;
+        }
+    }
+
+    fn child_mut_ref(&mut self, i: usize) -> PrefixRef<T, B, Mutable> {
+        NodeRef(self.child_ptr(i).unwrap(), Pd)
+    }
+}
+
 impl<T, const B: usize> NodeRef<InternalNode<T, B>> {
     pub fn child_ref(&self, i: usize) -> PrefixRef<T, B> {
         NodeRef(self.child_ptr(i).unwrap(), Pd)
     }
 }
 
-impl<T: Clone, const B: usize> NodeRef<InternalNode<T, B>, Mutable> {
-    pub fn clone_from(
+impl<U, const B: usize> NodeRef<InternalNode<U, B>, Mutable> {
+    /// Adds children to this node, which must be empty, that are created from
+    /// the children of `other`. See [`PrefixRef::map_node`].
+    ///
+    /// [`PrefixRef::map_node`]: super::PrefixRef::map_node
+    pub fn map_from<T, R, E, F>(
         &mut self,
-        other: InternalRef<T, B>,
-        mut next_leaf: Option<NonNull<LeafNode<T, B>>>,
+        other: NodeRef<InternalNode<T, B>, R>,
+        prev: &mut Option<NonNull<LeafNode<U, B>>>,
         alloc: &VerifiedAlloc<impl Allocator>,
-    ) -> NonNull<LeafNode<T, B>> {
-        let self_ptr = self.as_ptr();
-        for (i, c) in (0..other.length).zip(&mut self.children).rev() {
-            let (mut node, leaf) =
-                other.child_ref(i).clone_node(next_leaf, alloc);
-            next_leaf = Some(leaf);
-            node.parent.set(Some(self_ptr));
-            *c = Some(node.as_ptr());
+        fill: &mut F,
+    ) -> Result<(), E>
+    where
+        F: FnMut(
+            NodeRef<LeafNode<T, B>, R>,
+            &mut LeafNode<U, B>,
+        ) -> Result<(), E>,
+    {
+        for i in 0..other.length {
+            let child: PrefixRef<T, B, R> =
+                NodeRef(other.child_ptr(i).unwrap(), Pd);
+            let node = child.map_node(prev, alloc, fill)?;
+            self.simple_insert(i, (node, other.sizes[i]));
         }
-        self.length = other.length;
-        self.sizes = other.sizes;
-        next_leaf.expect("internal nodes should have at least one child")
+        Ok(())
     }
 }
@@ -1,5 +1,5 @@
 /*
- * Copyright (C) 2021-2022, 2025 taylor.fish <contact@taylor.fish>
+ * Copyright (C) 2021-2022, 2025-2026 taylor.fish <contact@taylor.fish>
  *
  * This file is part of btree-vec.
  *
@@ -32,6 +32,33 @@ pub struct LeafNode<T, const B: usize> {
     next: Option<NonNull<Self>>,
 }
 
+/// The state of a leaf from which items are being removed one at a time.
+/// See [`LeafNode::start_compaction`].
+pub struct Compaction {
+    length: usize,
+    read: usize,
+    write: usize,
+}
+
+impl Compaction {
+    /// Returns the number of items that haven't been visited.
+    pub fn remaining(&self) -> usize {
+        self.length - self.read
+    }
+}
+
+/// Ends compaction of a leaf when dropped.
+struct CompactionGuard<'a, T, const B: usize> {
+    leaf: &'a mut LeafNode<T, B>,
+    state: Compaction,
+}
+
+impl<T, const B: usize> Drop for CompactionGuard<'_, T, B> {
+    fn drop(&mut self) {
+        self.leaf.end_compaction(&self.state);
+    }
+}
+
 impl<T, const B: usize> Drop for LeafNode<T, B> {
     fn drop(&mut self) {
         for child in &mut self.children[..self.length] {
@@ -53,20 +80,6 @@ impl<T, const B: usize> LeafNode<T, B> {
         }
     }
 
-    pub fn clone_from(
-        &mut self,
-        other: &Self,
-        next_leaf: Option<NonNull<Self>>,
-    ) where
-        T: Clone,
-    {
-        for (md, item) in self.children.iter_mut().zip(other.children()) {
-            md.write(item.clone());
-        }
-        self.length = other.length;
-        self.next = next_leaf;
-    }
-
     pub fn split(
         &mut self,
         strategy: SplitStrategy,
@@ -111,6 +124,228 @@ impl<T, const B: usize> LeafNode<T, B> {
         other.length = 0;
     }
 
+    /// Moves the first `n` items in `next` to the end of this leaf.
+    pub fn take_front(&mut self, next: &mut Self, n: usize) {
+        let length = self.length;
+        assert!(length + n <= B);
+        assert!(n <= next.length);
+        let src = next.children.as_mut_ptr() as *mut T;
+        // SAFETY: Guaranteed by this type's invariants (length is always
+        // accurate) and the assertions above.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                src,
+                (self.children.as_mut_ptr() as *mut T).wrapping_add(length),
+                n,
+            );
+            ptr::copy(src.wrapping_add(n), src, next.length - n);
+        }
+        self.length += n;
+        next.length -= n;
+    }
+
+    /// Moves the last `n` items in `prev` to the start of this leaf.
+    pub fn take_back(&mut self, prev: &mut Self, n: usize) {
+        let length = self.length;
+        assert!(length + n <= B);
+        assert!(n <= prev.length);
+        let dest = self.children.as_mut_ptr() as *mut T;
+        // SAFETY: Guaranteed by this type's invariants (length is always
+        // accurate) and the assertions above.
+        unsafe {
+            ptr::copy(dest, dest.wrapping_add(n), length);
+            ptr::copy_nonoverlapping(
+                (prev.children.as_ptr() as *const T)
+                    .wrapping_add(prev.length - n),
+                dest,
+                n,
+            );
+        }
+        self.length += n;
+        prev.length -= n;
+    }
+
+    /// Moves all of the items in `next`, which must be the next leaf in the
+    /// chain, to the end of this leaf, and removes `next` from the chain.
+    pub fn absorb(&mut self, next: &mut Self) {
+        assert!(self.next == Some(NonNull::from(&mut *next)));
+        self.take_front(next, next.length);
+        self.next = next.next.take();
+    }
+
+    pub fn next_ptr(&self) -> Option<NonNull<Self>> {
+        self.next
+    }
+
+    /// Sets the leaf that follows this one in the leaf chain. The caller is
+    /// responsible for keeping the chain consistent with the tree.
+    pub fn set_next(&mut self, next: Option<NonNull<Self>>) {
+        self.next = next;
+    }
+
+    /// Starts visiting the items in this leaf in order so that some of them
+    /// can be removed with [`Self::compact_next`]. Until
+    /// [`Self::end_compaction`] is called, the leaf appears empty.
+    pub fn start_compaction(&mut self) -> Compaction {
+        Compaction {
+            length: mem::take(&mut self.length),
+            read: 0,
+            write: 0,
+        }
+    }
+
+    /// Returns the next item that hasn't been visited, if any.
+    pub fn peek_next(&self, state: &Compaction) -> Option<&T> {
+        (state.read < state.length).then(|| {
+            let ptr = self.children.as_ptr() as *const T;
+            // SAFETY: Items at `read..length` are initialized.
+            unsafe { &*ptr.wrapping_add(state.read) }
+        })
+    }
+
+    /// Visits the next item, which must exist, and removes and returns it if
+    /// `f` returns true. If `f` panics, the item is considered unvisited.
+    pub fn compact_next<F>(
+        &mut self,
+        state: &mut Compaction,
+        f: F,
+    ) -> Option<T>
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        assert!(state.read < state.length);
+        let ptr = self.children.as_mut_ptr() as *mut T;
+        let item = ptr.wrapping_add(state.read);
+        // SAFETY: Items at `read..length` are initialized, and no other
+        // references to them exist.
+        let remove = f(unsafe { &mut *item });
+        state.read += 1;
+        if remove {
+            // SAFETY: The item is initialized, and it won't be accessed again
+            // because `read` has been incremented.
+            return Some(unsafe { item.read() });
+        }
+        // SAFETY: `write < read`, so `write` is in bounds and was either
+        // already moved or is the item being moved.
+        unsafe {
+            ptr::copy(item, ptr.wrapping_add(state.write), 1);
+        }
+        state.write += 1;
+        None
+    }
+
+    /// Ends compaction, keeping the items that weren't visited.
+    pub fn end_compaction(&mut self, state: &Compaction) {
+        let ptr = self.children.as_mut_ptr() as *mut T;
+        let n = state.length - state.read;
+        // SAFETY: Items at `read..length` are initialized and haven't been
+        // visited, and `write <= read`.
+        unsafe {
+            ptr::copy(
+                ptr.wrapping_add(state.read),
+                ptr.wrapping_add(state.write),
+                n,
+            );
+        }
+        self.length = state.write + n;
+    }
+
+    /// Removes the items for which `f` returns false, preserving the order of
+    /// the remaining items. If `f` panics, the items not yet visited are
+    /// kept.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let state = self.start_compaction();
+        let mut guard = CompactionGuard {
+            leaf: self,
+            state,
+        };
+        while guard.state.remaining() > 0 {
+            let CompactionGuard {
+                leaf,
+                state,
+            } = &mut guard;
+            leaf.compact_next(state, |item| !f(item));
+        }
+    }
+
+    /// Removes the items in this leaf in order, calling `f` with each one,
+    /// until `f` returns an error. If `f` returns an error or panics, the
+    /// items not yet removed are kept.
+    pub fn take_each<E, F>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(T) -> Result<(), E>,
+    {
+        let state = self.start_compaction();
+        let mut guard = CompactionGuard {
+            leaf: self,
+            state,
+        };
+        while guard.state.remaining() > 0 {
+            let CompactionGuard {
+                leaf,
+                state,
+            } = &mut guard;
+            f(leaf.compact_next(state, |_| true).unwrap())?;
+        }
+        Ok(())
+    }
+
+    /// Removes the items for which `same_bucket` returns true when given the
+    /// item and the last item that was kept. `last` is the last item kept
+    /// before this leaf, if any. If `same_bucket` panics, the items not yet
+    /// visited are kept.
+    pub fn dedup_by<F>(&mut self, last: Option<&mut T>, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let state = self.start_compaction();
+        let mut guard = CompactionGuard {
+            leaf: self,
+            state,
+        };
+        let ptr = guard.leaf.children.as_mut_ptr() as *mut T;
+        let state = &mut guard.state;
+        let mut last = last.map(|item| item as *mut T);
+        while state.read < state.length {
+            let item = ptr.wrapping_add(state.read);
+            let remove = last.map_or(false, |last| {
+                // SAFETY: Items at `read..length` are initialized, `last` is
+                // either in another leaf or at an index less than `write`,
+                // and no other references to either item exist.
+                same_bucket(unsafe { &mut *item }, unsafe { &mut *last })
+            });
+            state.read += 1;
+            if remove {
+                // SAFETY: The item is initialized, and it won't be accessed
+                // again because `read` has been incremented.
+                unsafe {
+                    ptr::drop_in_place(item);
+                }
+                continue;
+            }
+            let dest = ptr.wrapping_add(state.write);
+            // SAFETY: `write < read`, so `write` is in bounds and was either
+            // already moved or is the item being moved.
+            unsafe {
+                ptr::copy(item, dest, 1);
+            }
+            state.write += 1;
+            last = Some(dest);
+        }
+    }
+
+    /// Reverses the order of the items in this leaf, and links this leaf to
+    /// `prev`, which is then set to this leaf. See [`PrefixRef::reverse`].
+    ///
+    /// [`PrefixRef::reverse`]: super::PrefixRef::reverse
+    pub fn reverse(&mut self, prev: &mut Option<NonNull<Self>>) {
+        self.children_mut().reverse();
+        self.next = prev.replace(NonNull::from(&mut *self));
+    }
+
     pub fn simple_insert(&mut self, i: usize, item: T) {
         let length = self.length;
         self.children[i..length + 1].rotate_right(1);
@@ -238,6 +473,14 @@ impl<T, const B: usize, R> NodeRef<LeafNode<T, B>, R> {
 }
 
 impl<T, const B: usize> NodeRef<LeafNode<T, B>, Mutable> {
+    /// Inserts `new`, which must not be part of any chain, after this leaf in
+    /// the leaf chain.
+    pub fn link(&mut self, new: &mut Self) {
+        assert!(new.next.is_none());
+        new.next = self.next;
+        self.next = Some(new.as_ptr());
+    }
+
     pub fn into_children_mut<'a>(mut self) -> &'a mut [T] {
         // SAFETY: The underlying node's life is not tied to this `NodeRef`'s
         // life, so we can return a reference to data in the node with any
@@ -30,7 +30,7 @@ mod leaf;
 mod parent_ptr;
 
 pub use internal::InternalNode;
-pub use leaf::LeafNode;
+pub use leaf::{Compaction, LeafNode};
 use parent_ptr::ParentPtr;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -203,32 +203,94 @@ impl<T, const B: usize, R> NodeRef<Prefix<T, B>, R> {
             }
         }
     }
-}
 
-impl<T: Clone, const B: usize> NodeRef<Prefix<T, B>> {
-    /// Returns the new node and the first leaf of the subtree rooted at the
-    /// node.
-    pub fn clone_node(
-        &self,
-        next_leaf: Option<NonNull<LeafNode<T, B>>>,
+    /// Creates a tree with the same shape as the subtree rooted at this node.
+    /// `fill` is called with each leaf in the subtree, in order, and the
+    /// corresponding new leaf, which it must fill with the same number of
+    /// items. The first new leaf is linked after `prev`, which is then set to
+    /// the last new leaf.
+    ///
+    /// If `fill` returns an error, the new nodes are destroyed and the error
+    /// is returned. The new nodes are also destroyed if `fill` panics.
+    pub fn map_node<U, E, F>(
+        self,
+        prev: &mut Option<NonNull<LeafNode<U, B>>>,
         alloc: &VerifiedAlloc<impl Allocator>,
-    ) -> (NodeRef<Prefix<T, B>, Mutable>, NonNull<LeafNode<T, B>>) {
+        fill: &mut F,
+    ) -> Result<PrefixRef<U, B, Mutable>, E>
+    where
+        F: FnMut(
+            NodeRef<LeafNode<T, B>, R>,
+            &mut LeafNode<U, B>,
+        ) -> Result<(), E>,
+    {
+        // Each new node is destroyed by `guard` if `fill` returns an error or
+        // panics before the node is returned.
         match self.cast() {
             PrefixCast::Internal(node) => {
                 let mut new = InternalRef::alloc(alloc);
-                let leaf = new.clone_from(node, next_leaf, alloc);
-                (new.into_prefix(), leaf)
+                let ptr: PrefixPtr<U, B> = new.as_ptr().cast();
+                // SAFETY: `guard` is disarmed before `new` is returned, so the
+                // node is destroyed only if we return early or unwind, after
+                // which `new` isn't used.
+                let mut guard = unsafe { Destroy::new(Some(ptr), alloc) };
+                new.map_from(node, prev, alloc, fill)?;
+                guard.root = None;
+                Ok(new.into_prefix())
             }
             PrefixCast::Leaf(node) => {
                 let mut new = LeafRef::alloc(alloc);
-                new.clone_from(&node, next_leaf);
-                let leaf = node.as_ptr();
-                (new.into_prefix(), leaf)
+                let ptr: PrefixPtr<U, B> = new.as_ptr().cast();
+                // SAFETY: See above.
+                let mut guard = unsafe { Destroy::new(Some(ptr), alloc) };
+                fill(node, &mut new)?;
+                guard.root = None;
+                if let Some(ptr) = prev.replace(new.as_ptr()) {
+                    // SAFETY: `ptr` is the previous leaf of the new tree,
+                    // which isn't part of any `BTreeVec` yet, so there are no
+                    // other references to it.
+                    unsafe { NodeRef::new_mutable(ptr) }
+                        .set_next(Some(new.as_ptr()));
+                }
+                Ok(new.into_prefix())
             }
         }
     }
 }
 
+/// Destroys a tree when dropped.
+pub struct Destroy<'a, T, const B: usize, A: Allocator> {
+    /// The root of the tree. The tree isn't destroyed if this is [`None`].
+    pub root: Option<PrefixPtr<T, B>>,
+    alloc: &'a VerifiedAlloc<A>,
+}
+
+impl<'a, T, const B: usize, A: Allocator> Destroy<'a, T, B, A> {
+    /// # Safety
+    ///
+    /// `root` must be the root of a tree allocated by `alloc`. Unless
+    /// [`Self::root`] is set to [`None`] first, there must be no other
+    /// references to the tree when the returned value is dropped.
+    pub unsafe fn new(
+        root: Option<PrefixPtr<T, B>>,
+        alloc: &'a VerifiedAlloc<A>,
+    ) -> Self {
+        Self {
+            root,
+            alloc,
+        }
+    }
+}
+
+impl<T, const B: usize, A: Allocator> Drop for Destroy<'_, T, B, A> {
+    fn drop(&mut self) {
+        if let Some(root) = self.root.take() {
+            // SAFETY: Guaranteed by the caller of `Self::new`.
+            unsafe { NodeRef::new_mutable(root) }.destroy(self.alloc);
+        }
+    }
+}
+
 impl<T, const B: usize> NodeRef<Prefix<T, B>, Mutable> {
     pub fn destroy(self, alloc: &VerifiedAlloc<impl Allocator>) {
         match self.cast() {
@@ -236,6 +298,17 @@ impl<T, const B: usize> NodeRef<Prefix<T, B>, Mutable> {
             PrefixCast::Leaf(node) => node.destroy(alloc),
         }
     }
+
+    /// Reverses the order of the items in the subtree rooted at this node,
+    /// along with the order of its children and leaves. The first leaf in the
+    /// subtree becomes the last and is linked to `prev`, which is then set to
+    /// the new first leaf.
+    pub fn reverse(self, prev: &mut Option<NonNull<LeafNode<T, B>>>) {
+        match self.cast() {
+            PrefixCast::Internal(mut node) => node.reverse(prev),
+            PrefixCast::Leaf(mut node) => node.reverse(prev),
+        }
+    }
 }
 
 impl<N, T, const B: usize> NodeRef<N, Mutable>
@@ -0,0 +1,168 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Partitioning of a vector into two vectors that reuse its leaves.
+
+use super::BTreeVec;
+use super::node::NodeRef;
+use super::node::{Compaction, LeafRef, Node};
+use super::rebuild::{LeafPtr, into_leaves, leaf_mut, rebuild};
+use crate::Allocator;
+
+/// The state of a partition. The leaves of the vector are detached from the
+/// tree while the partition is in progress. Items are moved from the front
+/// of the remaining leaves to the end of one of two output chains, which
+/// reuse the leaves that have been emptied.
+///
+/// When this type is dropped, a new tree is built in the vector from the
+/// first output chain, followed by the second output chain and the
+/// remaining leaves, if any.
+struct Partition<'a, T, const B: usize, A: Allocator> {
+    vec: &'a mut BTreeVec<T, B, A>,
+    /// The leaf whose items are being moved, which is followed by the rest
+    /// of the leaves that haven't been processed yet.
+    rest: Option<(LeafPtr<T, B>, Compaction)>,
+    /// The first and last leaves of the items that matched and didn't match
+    /// the predicate, respectively.
+    outs: [Option<(LeafPtr<T, B>, LeafPtr<T, B>)>; 2],
+    /// Empty leaves that can be reused for the output.
+    free: Option<LeafPtr<T, B>>,
+}
+
+impl<'a, T, const B: usize, A: Allocator> Partition<'a, T, B, A> {
+    fn new(vec: &'a mut BTreeVec<T, B, A>) -> Self {
+        vec.size = 0;
+        let rest = vec.root.take().map(|root| {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references.
+            let first =
+                into_leaves(unsafe { NodeRef::new_mutable(root) }, &vec.alloc);
+            Self::start(first)
+        });
+        Self {
+            vec,
+            rest,
+            outs: [None, None],
+            free: None,
+        }
+    }
+
+    fn start(ptr: LeafPtr<T, B>) -> (LeafPtr<T, B>, Compaction) {
+        // SAFETY: The remaining leaves aren't part of any tree, and we create
+        // only temporary references to them.
+        (ptr, unsafe { leaf_mut(ptr) }.start_compaction())
+    }
+
+    /// Appends `item` to the output chain at index `side`.
+    fn push(&mut self, side: usize, item: T) {
+        let out = &mut self.outs[side];
+        if let Some((_, last)) = out {
+            // SAFETY: The leaves in the output aren't part of any tree, and
+            // we create only temporary references to them.
+            let mut leaf = unsafe { leaf_mut(*last) };
+            let length = leaf.length();
+            if length < B {
+                leaf.simple_insert(length, item);
+                return;
+            }
+        }
+        let mut new = if let Some(ptr) = self.free {
+            // SAFETY: The free leaves aren't part of any tree, and we create
+            // only temporary references to them.
+            let mut leaf = unsafe { leaf_mut(ptr) };
+            self.free = leaf.next_ptr();
+            leaf.set_next(None);
+            leaf
+        } else {
+            LeafRef::alloc(&self.vec.alloc)
+        };
+        new.simple_insert(0, item);
+        let ptr = new.as_ptr();
+        match out {
+            Some((_, last)) => {
+                // SAFETY: See above.
+                unsafe { leaf_mut(*last) }.set_next(Some(ptr));
+                *last = ptr;
+            }
+            out => *out = Some((ptr, ptr)),
+        }
+    }
+}
+
+impl<T, const B: usize, A: Allocator> Drop for Partition<'_, T, B, A> {
+    fn drop(&mut self) {
+        let rest = self.rest.take().map(|(ptr, state)| {
+            // SAFETY: See `Self::start`.
+            unsafe { leaf_mut(ptr) }.end_compaction(&state);
+            ptr
+        });
+        let mut first = rest;
+        for (out_first, last) in self.outs.iter_mut().rev().flatten() {
+            // SAFETY: The leaves in the output aren't part of any tree, and
+            // there are no other references to them.
+            unsafe { leaf_mut(*last) }.set_next(first);
+            first = Some(*out_first);
+        }
+        while let Some(ptr) = self.free {
+            // SAFETY: The free leaves aren't part of any tree, and there are
+            // no other references to them.
+            let mut leaf = unsafe { leaf_mut(ptr) };
+            self.free = leaf.next_ptr();
+            leaf.set_next(None);
+            leaf.destroy(&self.vec.alloc);
+        }
+        rebuild(self.vec, first);
+    }
+}
+
+/// Moves the items in `vec` for which `pred` returns false to a new vector,
+/// which is returned. The leaves of `vec` are reused for both vectors.
+pub fn partition_off<T, const B: usize, A, F>(
+    vec: &mut BTreeVec<T, B, A>,
+    mut pred: F,
+) -> BTreeVec<T, B, A>
+where
+    A: Allocator + Clone,
+    F: FnMut(&T) -> bool,
+{
+    let mut partition = Partition::new(vec);
+    while let Some((ptr, state)) = &mut partition.rest {
+        let ptr = *ptr;
+        // SAFETY: See `Partition::start`.
+        let mut leaf = unsafe { leaf_mut(ptr) };
+        if state.remaining() == 0 {
+            leaf.end_compaction(state);
+            let next = leaf.next_ptr();
+            leaf.set_next(partition.free.replace(ptr));
+            partition.rest = next.map(Partition::<_, B, A>::start);
+            continue;
+        }
+        // The item is left in the leaf until `pred` returns, so that it isn't
+        // lost if `pred` panics.
+        let side = !pred(leaf.peek_next(state).unwrap()) as usize;
+        let item = leaf.compact_next(state, |_| true).unwrap();
+        partition.push(side, item);
+    }
+    let first = partition.outs[1].take().map(|(first, _)| first);
+    let mut other = BTreeVec::create_in((*partition.vec.alloc).clone());
+    // The leaves were allocated by `vec`'s allocator, and `other`'s allocator
+    // is a clone of it.
+    rebuild(&mut other, first);
+    other
+}
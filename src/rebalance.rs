@@ -0,0 +1,141 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Rebalancing for bulk operations, which, unlike single-item removals, can
+//! leave nodes with far fewer children than the minimum.
+//!
+//! All of the functions in this module require that the sizes stored in every
+//! node be accurate.
+
+use super::node::{InternalRef, Node, NodeRef, Prefix, PrefixCast};
+use super::node::{Mutable, PrefixRef};
+use crate::{Allocator, VerifiedAlloc};
+
+/// Ensures that child `index` of `parent` has at least `min` children, by
+/// moving children from or merging with a sibling.
+///
+/// If a merge occurs, `parent` is rebalanced too. Returns the node now
+/// containing the original child (or what it was merged into) and the index
+/// of that child.
+///
+/// If `parent` and all of its ancestors have only one child, the child is
+/// left as-is, as the chain will be removed by [`collapse`].
+pub fn rebalance<T, const B: usize>(
+    mut parent: InternalRef<T, B, Mutable>,
+    mut index: usize,
+    min: usize,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> (InternalRef<T, B, Mutable>, usize) {
+    if parent.child_length(index) >= min {
+        return (parent, index);
+    }
+
+    if parent.length() == 1 {
+        // The child has no siblings, so `parent` must gain children first.
+        (parent, index) = rebalance_parent(parent, index, 2.max(B / 2), alloc);
+        if parent.length() == 1 {
+            return (parent, index);
+        }
+    }
+
+    let left = index.saturating_sub(1);
+    let total = parent.child_length(left) + parent.child_length(left + 1);
+    if total > B {
+        let half = total / 2;
+        if index == left {
+            let n = half - parent.child_length(left);
+            parent.shift_left(left, n);
+        } else {
+            let n = half - parent.child_length(left + 1);
+            parent.shift_right(left, n);
+        }
+        return (parent, index);
+    }
+
+    parent.merge_children(left).destroy(alloc);
+    rebalance_parent(parent, left, B / 2, alloc)
+}
+
+/// Rebalances `node` within its parent. Returns the node now containing what
+/// was child `index` of `node`, and the new index of that child.
+fn rebalance_parent<T, const B: usize>(
+    node: InternalRef<T, B, Mutable>,
+    index: usize,
+    min: usize,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> (InternalRef<T, B, Mutable>, usize) {
+    let child = node.child_ptr(index);
+    let node_index = node.index();
+    let node = match node.into_parent() {
+        Ok(parent) => {
+            let (parent, i) = rebalance(parent, node_index, min, alloc);
+            match parent.into_child(i).cast() {
+                PrefixCast::Internal(node) => node,
+                PrefixCast::Leaf(_) => unreachable!(),
+            }
+        }
+        Err(root) => return (root, index),
+    };
+    let index = (0..node.length()).find(|&i| node.child_ptr(i) == child);
+    (node, index.unwrap())
+}
+
+/// Removes internal nodes at the top of the tree that have only one child.
+/// Returns the new root.
+pub fn collapse<T, const B: usize>(
+    mut root: PrefixRef<T, B, Mutable>,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> PrefixRef<T, B, Mutable> {
+    loop {
+        root = match root.cast() {
+            PrefixCast::Internal(mut node) if node.length() == 1 => {
+                let child = node.simple_remove(0).0;
+                node.destroy(alloc);
+                child
+            }
+            PrefixCast::Internal(node) => return node.into_prefix(),
+            PrefixCast::Leaf(node) => return node.into_prefix(),
+        };
+    }
+}
+
+/// Rebalances every node on the path from `node` to the root. Nodes not on
+/// this path must already have at least the minimum number of children.
+/// Returns the new root.
+pub fn rebalance_path<N, T, const B: usize>(
+    node: NodeRef<N, Mutable>,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> PrefixRef<T, B, Mutable>
+where
+    N: Node<Prefix = Prefix<T, B>>,
+{
+    let mut index = node.index();
+    let mut parent = match node.into_parent() {
+        Ok(parent) => parent,
+        Err(root) => return root.into_prefix(),
+    };
+    loop {
+        let (node, _) = rebalance(parent, index, B / 2, alloc);
+        index = node.index();
+        parent = match node.into_parent() {
+            Ok(parent) => parent,
+            Err(root) => return collapse(root.into_prefix(), alloc),
+        };
+    }
+}
@@ -0,0 +1,157 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Rebuilding of trees after operations that can remove items from any
+//! number of leaves.
+
+use super::BTreeVec;
+use super::extend::Tail;
+use super::leaf_for;
+use super::node::PrefixRef;
+use super::node::{LeafNode, LeafRef, Mutable, Node, NodeRef, PrefixCast};
+use crate::{Allocator, VerifiedAlloc};
+use core::ptr::NonNull;
+
+pub type LeafPtr<T, const B: usize> = NonNull<LeafNode<T, B>>;
+
+/// # Safety
+///
+/// `ptr` must point to a leaf that isn't part of any tree, and there must be
+/// no other references to the leaf while the returned [`NodeRef`] exists.
+pub unsafe fn leaf_mut<T, const B: usize>(
+    ptr: LeafPtr<T, B>,
+) -> LeafRef<T, B, Mutable> {
+    // SAFETY: Caller guarantees safety.
+    unsafe { NodeRef::new_mutable(ptr) }
+}
+
+/// Deallocates all of the internal nodes in the tree rooted at `root`,
+/// leaving only the leaves, which are still linked in a chain.
+fn strip<T, const B: usize>(
+    root: PrefixRef<T, B, Mutable>,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) {
+    let mut node = match root.cast() {
+        PrefixCast::Internal(node) => node,
+        PrefixCast::Leaf(_) => return,
+    };
+    while let Some(i) = node.length().checked_sub(1) {
+        strip(node.simple_remove(i).0, alloc);
+    }
+    node.destroy(alloc);
+}
+
+/// Deallocates the internal nodes in the tree rooted at `root` and returns
+/// the first leaf of the tree's chain of leaves, which are no longer part of
+/// any tree. The leaves should be given back to a vector with [`rebuild`].
+pub fn into_leaves<T, const B: usize>(
+    root: PrefixRef<T, B, Mutable>,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> NonNull<LeafNode<T, B>> {
+    let ptr = root.as_ptr();
+    let first = leaf_for(root, 0).0.as_ptr();
+    // SAFETY: The `NodeRef` used above has been consumed.
+    strip(unsafe { NodeRef::new_mutable(ptr) }, alloc);
+    first
+}
+
+/// Builds a tree in `vec`, which must be empty, from the chain of leaves
+/// starting at `first`, which must have been allocated by `vec`'s allocator.
+pub fn rebuild<T, const B: usize, A: Allocator>(
+    vec: &mut BTreeVec<T, B, A>,
+    first: Option<NonNull<LeafNode<T, B>>>,
+) {
+    // Items are merged or moved between adjacent leaves so that every leaf
+    // except the last has at least the minimum number of items. The last
+    // leaf is rebalanced by `Tail::finish`.
+    let mut tail = Tail::empty();
+    let mut next = first;
+    while let Some(ptr) = next {
+        // SAFETY: The leaves aren't part of any tree, and we don't create any
+        // other references to them while this one exists.
+        let mut leaf: NodeRef<_, Mutable> =
+            unsafe { NodeRef::new_mutable(ptr) };
+        while let Some(ptr) = leaf.next_ptr() {
+            // SAFETY: See above.
+            let mut next: NodeRef<_, Mutable> =
+                unsafe { NodeRef::new_mutable(ptr) };
+            if leaf.length() + next.length() <= B {
+                leaf.absorb(&mut next);
+                next.destroy(&vec.alloc);
+                continue;
+            }
+            if leaf.length() < B / 2 {
+                let n = B / 2 - leaf.length();
+                leaf.take_front(&mut next, n);
+            }
+            break;
+        }
+        next = leaf.next_ptr();
+        leaf.set_next(None);
+        tail.push_leaf(vec, leaf);
+    }
+    tail.finish(vec);
+}
+
+/// Takes the leaves out of a [`BTreeVec`] so that items can be removed from
+/// them directly, and builds a new tree from the leaves when dropped.
+///
+/// The vector is empty while this type exists.
+pub struct Rebuild<'a, T, const B: usize, A: Allocator> {
+    vec: &'a mut BTreeVec<T, B, A>,
+    /// The first leaf in the chain.
+    first: Option<NonNull<LeafNode<T, B>>>,
+}
+
+impl<'a, T, const B: usize, A: Allocator> Rebuild<'a, T, B, A> {
+    pub fn new(vec: &'a mut BTreeVec<T, B, A>) -> Self {
+        vec.size = 0;
+        let first = vec.root.take().map(|root| {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references.
+            into_leaves(unsafe { NodeRef::new_mutable(root) }, &vec.alloc)
+        });
+        Self {
+            vec,
+            first,
+        }
+    }
+
+    /// Calls `f` with each leaf in order.
+    pub fn for_each_leaf<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut LeafNode<T, B>),
+    {
+        let mut next = self.first;
+        while let Some(ptr) = next {
+            // SAFETY: The leaves aren't part of any tree, and we don't
+            // create any other references to them while this one exists.
+            let mut leaf: NodeRef<_, Mutable> =
+                unsafe { NodeRef::new_mutable(ptr) };
+            f(&mut leaf);
+            next = leaf.next_ptr();
+        }
+    }
+}
+
+impl<T, const B: usize, A: Allocator> Drop for Rebuild<'_, T, B, A> {
+    fn drop(&mut self) {
+        rebuild(self.vec, self.first.take());
+    }
+}
@@ -0,0 +1,305 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Merge sort that reuses the leaves of a tree.
+
+use super::BTreeVec;
+use super::node::{Compaction, LeafRef, Node, NodeRef};
+use super::rebuild::{LeafPtr, into_leaves, leaf_mut, rebuild};
+use crate::Allocator;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// A sorted chain of leaves.
+struct Run<T, const B: usize> {
+    first: LeafPtr<T, B>,
+    last: LeafPtr<T, B>,
+    /// The number of items in the run.
+    size: usize,
+}
+
+/// The remaining part of a run that is being merged. Items are read from
+/// the front of `leaf`, which is followed by the rest of the run.
+struct Cursor<T, const B: usize> {
+    leaf: LeafPtr<T, B>,
+    state: Compaction,
+    last: LeafPtr<T, B>,
+}
+
+impl<T, const B: usize> Cursor<T, B> {
+    /// Creates a cursor for the chain of leaves from `leaf` to `last`.
+    fn new(leaf: LeafPtr<T, B>, last: LeafPtr<T, B>) -> Self {
+        // SAFETY: The leaves in a run aren't part of any tree, and `Sort`
+        // creates only temporary references to them.
+        let state = unsafe { leaf_mut(leaf) }.start_compaction();
+        Self {
+            leaf,
+            state,
+            last,
+        }
+    }
+
+    /// Ends compaction of the current leaf and returns the remaining items
+    /// as a chain of leaves.
+    fn finish(self) -> (LeafPtr<T, B>, LeafPtr<T, B>) {
+        // SAFETY: See `Self::new`.
+        unsafe { leaf_mut(self.leaf) }.end_compaction(&self.state);
+        (self.leaf, self.last)
+    }
+}
+
+/// A merge of two runs in progress.
+struct Merge<T, const B: usize> {
+    /// The remaining parts of the left and right runs. A side is [`None`]
+    /// once all of its items have been moved to `out`.
+    sides: [Option<Cursor<T, B>>; 2],
+    /// The first and last leaves of the merged chain so far.
+    out: Option<(LeafPtr<T, B>, LeafPtr<T, B>)>,
+    /// The total number of items in both runs.
+    size: usize,
+}
+
+/// The state of a sort. The leaves of the vector are detached from the tree
+/// while the sort is in progress, and a new tree is built from them when
+/// this type is dropped, even if the comparison function panics.
+struct Sort<'a, T, const B: usize, A: Allocator> {
+    vec: &'a mut BTreeVec<T, B, A>,
+    /// The leaves that haven't been added to a run yet.
+    rest: Option<LeafPtr<T, B>>,
+    /// Sorted runs, in order. Each run is at most as large as the one before
+    /// it.
+    runs: Vec<Run<T, B>>,
+    merge: Option<Merge<T, B>>,
+    /// Empty leaves that can be reused for the output of a merge.
+    free: Option<LeafPtr<T, B>>,
+}
+
+impl<'a, T, const B: usize, A: Allocator> Sort<'a, T, B, A> {
+    fn new(vec: &'a mut BTreeVec<T, B, A>) -> Self {
+        vec.size = 0;
+        let rest = vec.root.take().map(|root| {
+            // SAFETY: `BTreeVec` uses `NodeRef`s in accordance with standard
+            // borrowing rules, so there are no existing references.
+            into_leaves(unsafe { NodeRef::new_mutable(root) }, &vec.alloc)
+        });
+        Self {
+            vec,
+            rest,
+            runs: Vec::new(),
+            merge: None,
+            free: None,
+        }
+    }
+
+    /// Appends `item` to the output of the current merge.
+    fn push(&mut self, item: T) {
+        let merge = self.merge.as_mut().unwrap();
+        if let Some((_, last)) = &mut merge.out {
+            // SAFETY: The leaves in the output aren't part of any tree, and
+            // we create only temporary references to them.
+            let mut leaf = unsafe { leaf_mut(*last) };
+            let length = leaf.length();
+            if length < B {
+                leaf.simple_insert(length, item);
+                return;
+            }
+        }
+        let mut new = if let Some(ptr) = self.free {
+            // SAFETY: The free leaves aren't part of any tree, and we create
+            // only temporary references to them.
+            let mut leaf = unsafe { leaf_mut(ptr) };
+            self.free = leaf.next_ptr();
+            leaf.set_next(None);
+            leaf
+        } else {
+            LeafRef::alloc(&self.vec.alloc)
+        };
+        new.simple_insert(0, item);
+        let ptr = new.as_ptr();
+        match &mut merge.out {
+            Some((_, last)) => {
+                // SAFETY: See above.
+                unsafe { leaf_mut(*last) }.set_next(Some(ptr));
+                *last = ptr;
+            }
+            out => *out = Some((ptr, ptr)),
+        }
+    }
+
+    /// Merges the last two runs.
+    fn merge<F>(&mut self, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let right = self.runs.pop().unwrap();
+        let left = self.runs.pop().unwrap();
+        self.merge = Some(Merge {
+            size: left.size + right.size,
+            sides: [
+                Some(Cursor::new(left.first, left.last)),
+                Some(Cursor::new(right.first, right.last)),
+            ],
+            out: None,
+        });
+        loop {
+            let merge = self.merge.as_mut().unwrap();
+            for side in &mut merge.sides {
+                let cursor = if let Some(cursor) = side {
+                    cursor
+                } else {
+                    continue;
+                };
+                if cursor.state.remaining() > 0 {
+                    continue;
+                }
+                let ptr = cursor.leaf;
+                // SAFETY: The leaves in a run aren't part of any tree, and we
+                // create only temporary references to them.
+                let mut leaf = unsafe { leaf_mut(ptr) };
+                leaf.end_compaction(&cursor.state);
+                let next = leaf.next_ptr();
+                leaf.set_next(self.free.replace(ptr));
+                *side = next.map(|next| Cursor::new(next, cursor.last));
+            }
+            let [left, right] = &mut merge.sides;
+            let take_right = match (&*left, &*right) {
+                (Some(left), Some(right)) => {
+                    // SAFETY: See above.
+                    let (l, r) =
+                        unsafe { (left.leaf.as_ref(), right.leaf.as_ref()) };
+                    let l = l.peek_next(&left.state).unwrap();
+                    let r = r.peek_next(&right.state).unwrap();
+                    compare(r, l) == Ordering::Less
+                }
+                _ => break,
+            };
+            let cursor = if take_right {
+                right.as_mut().unwrap()
+            } else {
+                left.as_mut().unwrap()
+            };
+            // SAFETY: See above.
+            let mut leaf = unsafe { leaf_mut(cursor.leaf) };
+            let item = leaf.compact_next(&mut cursor.state, |_| true);
+            self.push(item.unwrap());
+        }
+        // At most one side has items left, which are appended to the output
+        // without being moved.
+        let mut merge = self.merge.take().unwrap();
+        let rest = merge.sides.iter_mut().find_map(|side| side.take());
+        let (first, last) = match (merge.out, rest.map(Cursor::finish)) {
+            (Some((first, last)), Some((next, rest_last))) => {
+                // SAFETY: See above.
+                unsafe { leaf_mut(last) }.set_next(Some(next));
+                (first, rest_last)
+            }
+            (out, rest) => out.or(rest).unwrap(),
+        };
+        self.runs.push(Run {
+            first,
+            last,
+            size: merge.size,
+        });
+    }
+}
+
+impl<T, const B: usize, A: Allocator> Drop for Sort<'_, T, B, A> {
+    fn drop(&mut self) {
+        // All of the leaves are linked into a single chain, from which the
+        // tree is rebuilt. If the sort didn't finish, the items will be in an
+        // unspecified order.
+        let mut first = None;
+        let mut last: Option<LeafPtr<T, B>> = None;
+        let mut append = |chain: Option<LeafPtr<T, B>>| {
+            if let Some(last) = last {
+                // SAFETY: None of the leaves are part of any tree, and we
+                // create only temporary references to them.
+                unsafe { leaf_mut(last) }.set_next(chain);
+            } else {
+                first = chain;
+            }
+            let mut next = chain;
+            while let Some(ptr) = next {
+                last = Some(ptr);
+                // SAFETY: See above.
+                next = unsafe { ptr.as_ref() }.next_ptr();
+            }
+        };
+        if let Some(merge) = self.merge.take() {
+            append(merge.out.map(|(first, _)| first));
+            for cursor in merge.sides.into_iter().flatten() {
+                append(Some(cursor.finish().0));
+            }
+        }
+        for run in self.runs.drain(..) {
+            append(Some(run.first));
+        }
+        append(self.rest.take());
+        while let Some(ptr) = self.free {
+            // SAFETY: The free leaves aren't part of any tree, and there are
+            // no other references to them.
+            let mut leaf = unsafe { leaf_mut(ptr) };
+            self.free = leaf.next_ptr();
+            leaf.set_next(None);
+            leaf.destroy(&self.vec.alloc);
+        }
+        rebuild(self.vec, first);
+    }
+}
+
+/// Sorts the items in `vec` with a merge sort that reuses the vector's
+/// leaves. Each leaf is sorted in place (stably if `stable` is true), and
+/// the leaves are then merged.
+pub fn sort_by<T, const B: usize, A, F>(
+    vec: &mut BTreeVec<T, B, A>,
+    stable: bool,
+    mut compare: F,
+) where
+    A: Allocator,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut sort = Sort::new(vec);
+    while let Some(ptr) = sort.rest {
+        // SAFETY: The leaves aren't part of any tree, and we create only
+        // temporary references to them.
+        let mut leaf = unsafe { leaf_mut(ptr) };
+        sort.rest = leaf.next_ptr();
+        leaf.set_next(None);
+        sort.runs.push(Run {
+            first: ptr,
+            last: ptr,
+            size: leaf.length(),
+        });
+        let items = leaf.children_mut();
+        if stable {
+            items.sort_by(&mut compare);
+        } else {
+            items.sort_unstable_by(&mut compare);
+        }
+        while let [.., a, b] = &sort.runs[..] {
+            if a.size > b.size {
+                break;
+            }
+            sort.merge(&mut compare);
+        }
+    }
+    while sort.runs.len() > 1 {
+        sort.merge(&mut compare);
+    }
+}
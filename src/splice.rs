@@ -0,0 +1,158 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::drain::RawDrain;
+use super::extend::{Extender, Reattach};
+use super::{BTreeVec, to_range};
+use crate::{Allocator, Global};
+use core::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator};
+use core::ops::{Range, RangeBounds};
+
+/// A splicing iterator for a [`BTreeVec`].
+///
+/// This struct is created by [`BTreeVec::splice`]. It yields the removed
+/// items, and inserts the replacement items when dropped.
+pub struct Splice<'a, I, const B: usize, A = Global>
+where
+    I: Iterator,
+    A: Allocator,
+{
+    drain: RawDrain<I::Item, B>,
+    /// Holds the items after the removed range, which are added back after
+    /// the replacement items.
+    reattach: Reattach<'a, I::Item, B, A>,
+    replace_with: I,
+}
+
+impl<'a, I, const B: usize, A> Splice<'a, I, B, A>
+where
+    I: Iterator,
+    A: Allocator,
+{
+    pub(crate) fn new<R>(
+        vec: &'a mut BTreeVec<I::Item, B, A>,
+        range: R,
+        replace_with: I,
+    ) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range {
+            start,
+            end,
+        } = to_range(range, vec.len());
+        let reattach = Reattach::new(vec, end);
+        let root = reattach.vec.take_range(start..end);
+        Self {
+            drain: RawDrain::new(root, end - start),
+            reattach,
+            replace_with,
+        }
+    }
+}
+
+impl<I, const B: usize, A> Iterator for Splice<'_, I, B, A>
+where
+    I: Iterator,
+    A: Allocator,
+{
+    type Item = I::Item;
+
+    /// # Time complexity
+    ///
+    /// Constant.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<I, const B: usize, A> DoubleEndedIterator for Splice<'_, I, B, A>
+where
+    I: Iterator,
+    A: Allocator,
+{
+    /// # Time complexity
+    ///
+    /// Worst-case Θ(log *k*), where *k* is the number of items in the
+    /// removed range, but iteration over the entire range by repeatedly
+    /// calling this method is only Θ(*k*).
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<I, const B: usize, A> ExactSizeIterator for Splice<'_, I, B, A>
+where
+    I: Iterator,
+    A: Allocator,
+{
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(Some(lower), upper);
+        lower
+    }
+}
+
+impl<I, const B: usize, A> FusedIterator for Splice<'_, I, B, A>
+where
+    I: Iterator,
+    A: Allocator,
+{
+}
+
+// SAFETY: This type owns the removed items and the replacement iterator, so
+// it can be `Send` as long as `T` and `I` are `Send`.
+unsafe impl<I, const B: usize, A> Send for Splice<'_, I, B, A>
+where
+    I: Iterator + Send,
+    I::Item: Send,
+    A: Allocator,
+{
+}
+
+// SAFETY: The only `&self` methods on this type, `size_hint` and `len`, read
+// only the count of removed items that haven't been taken. They don't access
+// `replace_with`, any items, or the vector.
+unsafe impl<I, const B: usize, A> Sync for Splice<'_, I, B, A>
+where
+    I: Iterator,
+    A: Allocator,
+{
+}
+
+impl<I, const B: usize, A> Drop for Splice<'_, I, B, A>
+where
+    I: Iterator,
+    A: Allocator,
+{
+    fn drop(&mut self) {
+        let vec = &mut *self.reattach.vec;
+        let free = self.drain.finish_into_leaves(&vec.alloc);
+        // The leaves that held the removed items are filled with the
+        // replacement items before any new leaves are allocated.
+        let mut extender = Extender::reusing(vec, free);
+        self.replace_with.by_ref().for_each(|item| extender.push(item));
+        // The items after the removed range are added back when `reattach`
+        // is dropped.
+    }
+}
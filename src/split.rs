@@ -0,0 +1,81 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::leaf_for;
+use super::node::PrefixRef;
+use super::node::{InternalRef, LeafRef, Mutable, Node, NodeRef, Prefix};
+use super::rebalance::rebalance_path;
+use crate::{Allocator, VerifiedAlloc};
+
+/// Splits the tree rooted at `root` so that the items before `at` stay in
+/// the original tree and the remaining items are moved to a new tree. `at`
+/// must be greater than zero and less than the size of the tree. Returns the
+/// roots of the left and right trees.
+///
+/// Every node on the path from the root to the leaf containing the item at
+/// `at` is split in two, and both of the resulting paths are rebalanced.
+pub fn split<T, const B: usize>(
+    root: PrefixRef<T, B, Mutable>,
+    at: usize,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) -> (PrefixRef<T, B, Mutable>, PrefixRef<T, B, Mutable>) {
+    let (mut left, index) = leaf_for(root, at);
+    let mut right = LeafRef::alloc(alloc);
+    let size = left.length() - index;
+    right.take_back(&mut left, size);
+    right.set_next(left.next_ptr());
+    left.set_next(None);
+    let (left_ptr, right_ptr) = (left.as_ptr(), right.as_ptr());
+    split_parents(left, index, right, size, alloc);
+
+    // SAFETY: All of the `NodeRef`s used above have been consumed, so these
+    // are the only references to the leaves.
+    let (left, right) = unsafe {
+        (NodeRef::new_mutable(left_ptr), NodeRef::new_mutable(right_ptr))
+    };
+    (rebalance_path(left, alloc), rebalance_path(right, alloc))
+}
+
+/// `right` was split from `left`, and contains `right_size` items. `left`
+/// contains `left_size` items. The parent of `left`, and all of its
+/// ancestors, are split so that `right` and the nodes after it are moved to
+/// the new nodes.
+fn split_parents<N, T, const B: usize>(
+    left: NodeRef<N, Mutable>,
+    left_size: usize,
+    right: NodeRef<N, Mutable>,
+    right_size: usize,
+    alloc: &VerifiedAlloc<impl Allocator>,
+) where
+    N: Node<Prefix = Prefix<T, B>>,
+{
+    let index = left.index();
+    let mut parent = if let Ok(parent) = left.into_parent() {
+        parent
+    } else {
+        return;
+    };
+    let mut new = InternalRef::alloc(alloc);
+    let n = parent.length() - index - 1;
+    let moved = new.take_back(&mut parent, n);
+    new.simple_insert(0, (right.into_prefix(), right_size));
+    parent.sizes[index] = left_size;
+    let parent_size = parent.sizes[..=index].iter().sum();
+    split_parents(parent, parent_size, new, right_size + moved, alloc);
+}
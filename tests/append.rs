@@ -0,0 +1,123 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+
+#[test]
+fn empty() {
+    let mut a = BTreeVec::<u8, 4>::create();
+    let mut b: BTreeVec<u8, 4> = (0..10).collect();
+    a.append_nodes(&mut b);
+    assert!(b.is_empty());
+    assert_matches(&a, 0..10);
+    a.append_nodes(&mut b);
+    assert_matches(&a, 0..10);
+    b.push(10);
+    assert_matches(&b, 10..11);
+}
+
+#[test]
+fn same_height() {
+    let mut a: BTreeVec<u32, 5> = (0..40).collect();
+    let mut b: BTreeVec<u32, 5> = (40..80).collect();
+    a.append_nodes(&mut b);
+    assert!(a.len() == 80);
+    assert_matches(&a, 0..80);
+}
+
+#[test]
+fn taller_left() {
+    let mut a: BTreeVec<u32, 4> = (0..1000).collect();
+    for n in [1, 2, 3, 10, 50] {
+        let start = a.len() as u32;
+        let mut b: BTreeVec<u32, 4> = (start..start + n).collect();
+        a.append_nodes(&mut b);
+        assert_matches(&a, 0..start + n);
+    }
+    assert_matches(&a, 0..1066);
+    for i in (0..1066).rev() {
+        assert!(a.remove(i) == i as u32);
+    }
+}
+
+#[test]
+fn taller_right() {
+    let mut a: BTreeVec<u32, 6> = (100..1000).collect();
+    for n in [1, 2, 3, 10, 84] {
+        let end = 1000 - a.len() as u32;
+        let mut b: BTreeVec<u32, 6> = (end - n..end).collect();
+        b.append_nodes(&mut a);
+        assert_matches(&b, end - n..1000);
+        a = b;
+    }
+    assert_matches(&a, 0..1000);
+    for i in 0..1000 {
+        assert!(a.remove(0) == i);
+    }
+}
+
+#[test]
+fn repeated() {
+    let mut a = BTreeVec::<u32, 7>::create();
+    let mut start = 0;
+    for n in (0..300).map(|i| i % 37) {
+        let mut b: BTreeVec<u32, 7> = (start..start + n).collect();
+        a.append_nodes(&mut b);
+        assert_matches(&a, 0..start + n);
+        start += n;
+    }
+    assert!(a.len() == start as usize);
+}
+
+#[test]
+fn boxes() {
+    let mut a: BTreeVec<_, 4> = (0..100_u32).map(Box::new).collect();
+    let mut b: BTreeVec<_, 4> = (100..110_u32).map(Box::new).collect();
+    a.append_nodes(&mut b);
+    assert_matches(&a, (0..110).map(Box::new));
+    let mut c: BTreeVec<_, 4> = (110..500_u32).map(Box::new).collect();
+    a.append_nodes(&mut c);
+    assert_matches(&a, (0..500).map(Box::new));
+    assert!(a.into_iter().map(|b| *b).eq(0..500));
+}
+
+#[test]
+fn moved_items() {
+    for (n, m) in [(0, 0), (0, 10), (10, 0), (40, 40), (1000, 3), (3, 1000)] {
+        let mut a: BTreeVec<u32, 5> = (0..n).collect();
+        let mut b: BTreeVec<u32, 5> = (n..n + m).collect();
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_matches(&a, 0..n + m);
+        b.push(0);
+        assert_matches(&b, 0..1);
+    }
+}
+
+#[test]
+fn moved_boxes() {
+    let mut a: BTreeVec<_, 4> = (0..100_u32).map(Box::new).collect();
+    let mut b: BTreeVec<_, 4> = (100..300_u32).map(Box::new).collect();
+    a.append(&mut b);
+    assert!(b.is_empty());
+    assert_matches(&a, (0..300).map(Box::new));
+}
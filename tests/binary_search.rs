@@ -0,0 +1,90 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use btree_vec::BTreeVec;
+
+#[test]
+fn binary_search() {
+    let vec: BTreeVec<u32, 4> = (0..1000).map(|n| n * 2).collect();
+    for n in 0..2000 {
+        let expected = if n % 2 == 0 {
+            Ok(n as usize / 2)
+        } else {
+            Err(n as usize / 2 + 1)
+        };
+        assert!(vec.binary_search(&n) == expected);
+    }
+    assert!(vec.binary_search(&5000) == Err(1000));
+    assert!(BTreeVec::<u32, 4>::create().binary_search(&1) == Err(0));
+}
+
+#[test]
+fn duplicates() {
+    let items: Vec<u32> = (0..500).flat_map(|n| [n; 3]).collect();
+    let vec: BTreeVec<u32, 5> = items.iter().copied().collect();
+    for n in 0..500 {
+        let index = vec.binary_search(&n).unwrap();
+        assert!(vec[index] == n);
+        assert!(vec.partition_point(|&m| m < n) == n as usize * 3);
+        assert!(vec.partition_point(|&m| m <= n) == n as usize * 3 + 3);
+    }
+}
+
+#[test]
+fn by_key() {
+    let vec: BTreeVec<(u32, char), 6> =
+        (0..300).map(|n| (n * 3, 'a')).collect();
+    assert!(vec.binary_search_by_key(&150, |&(n, _)| n) == Ok(50));
+    assert!(vec.binary_search_by_key(&151, |&(n, _)| n) == Err(51));
+    let result = vec.binary_search_by(|&(n, _)| n.cmp(&897));
+    assert!(result == Ok(299));
+}
+
+#[test]
+fn borrowed_key() {
+    let vec: BTreeVec<String, 4> =
+        (100..400).map(|n| format!("item {n}")).collect();
+    let key = "item 250";
+    assert!(vec.binary_search_by_key(&key, |s| s.as_str()) == Ok(150));
+    let key = "item 2500";
+    assert!(vec.binary_search_by_key(&key, |s| s.as_str()) == Err(151));
+    let result = vec.binary_search_by(|s| s.as_str().cmp("item 099"));
+    assert!(result == Err(0));
+}
+
+#[test]
+fn partition_point() {
+    for len in [0, 1, 2, 5, 100, 1000] {
+        let vec: BTreeVec<u32, 4> = (0..len).collect();
+        for split in 0..=len {
+            assert!(vec.partition_point(|&n| n < split) == split as usize);
+        }
+    }
+}
+
+#[test]
+fn comparisons() {
+    let vec: BTreeVec<u32, 12> = (0..100_000).collect();
+    let mut calls = 0;
+    vec.partition_point(|&n| {
+        calls += 1;
+        n < 54321
+    });
+    assert!(calls <= 40);
+}
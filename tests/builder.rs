@@ -0,0 +1,117 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::{BTreeVec, BTreeVecBuilder};
+use common::{assert_matches, validate};
+
+#[test]
+fn empty() {
+    let vec = BTreeVecBuilder::<u8>::new().build();
+    validate(&vec);
+    assert!(vec.is_empty());
+    let vec = BTreeVec::<u8, 5>::from_exact_iter(core::iter::empty());
+    validate(&vec);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn basic_builder() {
+    let mut builder = BTreeVecBuilder::<u32, 6>::create();
+    for i in 0..100 {
+        builder.push(i);
+    }
+    builder.extend(100..500);
+    assert!(builder.len() == 500);
+    let vec = builder.build();
+    assert!(vec.len() == 500);
+    assert_matches(&vec, 0..500);
+}
+
+#[test]
+fn leaf_fill() {
+    for fill in 3..=7 {
+        let mut builder = BTreeVecBuilder::<u32, 7>::create().leaf_fill(fill);
+        builder.extend(0..200);
+        let mut vec = builder.build();
+        assert_matches(&vec, 0..200);
+        for i in 0..50 {
+            assert!(vec.remove(i as usize) == i * 2);
+        }
+        vec.insert(0, 1000);
+        assert!(vec.len() == 151);
+    }
+}
+
+#[cfg(btree_vec_debug)]
+#[test]
+fn leaf_fill_pattern() {
+    for fill in 3..=7 {
+        for n in [1, 2, 3, 7, 8, 29, 50, 71, 200, 701] {
+            let mut builder =
+                BTreeVecBuilder::<u32, 7>::create().leaf_fill(fill);
+            builder.extend(0..n);
+            let vec = builder.build();
+            assert_matches(&vec, 0..n);
+            let lengths = vec.leaf_lengths();
+            assert!(lengths.iter().sum::<usize>() == n as usize);
+            let last = lengths.len().saturating_sub(2);
+            assert!(lengths[..last].iter().all(|&len| len == fill));
+        }
+    }
+    let mut builder = BTreeVecBuilder::<u32, 7>::create();
+    builder.extend(0..22);
+    let vec = builder.build();
+    assert!(vec.leaf_lengths() == [7, 7, 4, 4]);
+    let mut builder = BTreeVecBuilder::<u32, 7>::create().leaf_fill(3);
+    builder.extend(0..10);
+    let vec = builder.build();
+    assert!(vec.leaf_lengths() == [3, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn leaf_fill_too_small() {
+    let _ = BTreeVecBuilder::<u8, 8>::create().leaf_fill(3);
+}
+
+#[test]
+#[should_panic]
+fn leaf_fill_too_large() {
+    let _ = BTreeVecBuilder::<u8, 8>::create().leaf_fill(9);
+}
+
+#[test]
+fn from_exact_iter() {
+    for n in [1, 4, 5, 9, 24, 25, 26, 1000] {
+        let vec = BTreeVec::<u32, 5>::from_exact_iter(0..n);
+        assert!(vec.len() == n as usize);
+        assert_matches(&vec, 0..n);
+    }
+}
+
+#[test]
+fn from_exact_iter_boxes() {
+    let items: Vec<_> = (0..100_u32).map(Box::new).collect();
+    let mut vec = BTreeVec::<_, 4>::from_exact_iter(items);
+    assert_matches(&vec, (0..100).map(Box::new));
+    vec.push(Box::new(100));
+    assert!(vec.into_iter().map(|b| *b).eq(0..101));
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use btree_vec::BTreeVec;
+use std::borrow::Borrow;
+use std::cell::Cell;
+
+/// Checks that `vec` contains the items in `expected`, in order, and, when
+/// compiled with `--cfg btree_vec_debug`, that its tree is valid.
+pub fn assert_matches<T, const B: usize, I>(vec: &BTreeVec<T, B>, expected: I)
+where
+    T: PartialEq,
+    I: IntoIterator,
+    I::Item: Borrow<T>,
+{
+    validate(vec);
+    let mut iter = vec.iter();
+    for item in expected {
+        assert!(iter.next() == Some(item.borrow()));
+    }
+    assert!(iter.next().is_none());
+}
+
+/// Checks that the tree in `vec` is valid, when compiled with
+/// `--cfg btree_vec_debug`.
+pub fn validate<T, const B: usize>(vec: &BTreeVec<T, B>) {
+    #[cfg(btree_vec_debug)]
+    vec.validate();
+    #[cfg(not(btree_vec_debug))]
+    let _ = vec;
+}
+
+/// Counts how many of the [`Dropped`] items it creates have been dropped.
+#[derive(Default)]
+pub struct DropCounter(Cell<usize>);
+
+impl DropCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an item with the given value that is counted when dropped.
+    pub fn item(&self, value: u32) -> Dropped<'_> {
+        Dropped {
+            value,
+            panic: false,
+            counter: self,
+        }
+    }
+
+    /// Returns the number of items that have been dropped.
+    pub fn get(&self) -> usize {
+        self.0.get()
+    }
+}
+
+/// An item that increments its [`DropCounter`] when dropped, and then panics
+/// if `panic` is true.
+pub struct Dropped<'a> {
+    pub value: u32,
+    pub panic: bool,
+    counter: &'a DropCounter,
+}
+
+impl Drop for Dropped<'_> {
+    fn drop(&mut self) {
+        self.counter.0.set(self.counter.0.get() + 1);
+        if self.panic {
+            panic!("drop panicked");
+        }
+    }
+}
@@ -0,0 +1,101 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::{BTreeVec, Concat};
+use common::assert_matches;
+
+fn chunks<const B: usize>(sizes: &[u32]) -> Vec<BTreeVec<u32, B>> {
+    let mut start = 0;
+    sizes
+        .iter()
+        .map(|&n| {
+            start += n;
+            (start - n..start).collect()
+        })
+        .collect()
+}
+
+#[test]
+fn nested_concat() {
+    let sizes = [0, 1, 5, 100, 0, 3, 250, 7, 0];
+    let outer: BTreeVec<BTreeVec<u32, 4>, 5> =
+        chunks(&sizes).into_iter().collect();
+    let vec = outer.clone().concat();
+    assert_matches(&vec, 0..366);
+    let vec = outer.concat_nodes();
+    assert_matches(&vec, 0..366);
+}
+
+#[test]
+fn nested_many() {
+    let outer: BTreeVec<BTreeVec<u32, 6>, 4> =
+        chunks(&[3; 2000]).into_iter().collect();
+    let vec = outer.clone().concat();
+    assert!(vec.len() == 6000);
+    assert_matches(&vec, 0..6000);
+    let vec = outer.concat_nodes();
+    assert_matches(&vec, 0..6000);
+}
+
+#[test]
+fn nested_join() {
+    let outer: BTreeVec<BTreeVec<u32, 4>, 4> =
+        chunks(&[10, 0, 20]).into_iter().collect();
+    let vec = outer.clone().join(&[100, 101]);
+    let expected = (0..10).chain([100, 101, 100, 101]).chain(10..30);
+    assert_matches(&vec, expected.clone());
+    let vec = outer.join_nodes(&[100, 101]);
+    assert_matches(&vec, expected);
+    let empty = BTreeVec::<BTreeVec<u32, 4>, 4>::create();
+    assert!(empty.clone().join(&[1]).is_empty());
+    assert!(empty.join_nodes(&[1]).is_empty());
+}
+
+#[test]
+fn nested_boxes() {
+    let outer: BTreeVec<BTreeVec<Box<u32>, 4>, 4> = (0..5)
+        .map(|i| (i * 10..i * 10 + 10).map(Box::new).collect())
+        .collect();
+    let vec = outer.clone().join(&[Box::new(99)]);
+    let expected = (0..5).flat_map(|i| (i * 10..i * 10 + 10).chain([99]));
+    let expected: Vec<_> = expected.take(54).map(Box::new).collect();
+    assert_matches(&vec, &expected);
+    let vec = outer.concat();
+    assert_matches(&vec, (0..50).map(Box::new));
+}
+
+#[test]
+fn slice_concat() {
+    let vecs = chunks::<5>(&[40, 0, 1, 300]);
+    let vec = vecs.concat();
+    assert_matches(&vec, 0..341);
+    assert_matches(&vecs[0], 0..40);
+    assert!(vecs[..0].concat().is_empty());
+}
+
+#[test]
+fn slice_join() {
+    let vecs: Vec<BTreeVec<Box<u32>, 6>> =
+        (0..3).map(|i| (i * 10..i * 10 + 5).map(Box::new).collect()).collect();
+    let vec = vecs.join(&[Box::new(99)]);
+    let expected = (0..5).chain([99]).chain(10..15).chain([99]).chain(20..25);
+    assert_matches(&vec, expected.map(Box::new));
+}
@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches, validate};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn dedup() {
+    let items =
+        (0..300).flat_map(|n| std::iter::repeat(n).take(n as usize % 7 + 1));
+    let mut vec: BTreeVec<u32, 5> = items.collect();
+    vec.dedup();
+    assert_matches(&vec, 0..300);
+    vec.insert(10, 1000);
+    assert!(vec.len() == 301);
+}
+
+#[test]
+fn across_leaves() {
+    let mut vec: BTreeVec<u32, 4> = std::iter::repeat(1).take(100).collect();
+    vec.push(2);
+    vec.extend(std::iter::repeat(3).take(50));
+    vec.dedup();
+    assert_matches(&vec, [1, 2, 3]);
+    let mut vec = BTreeVec::<u32, 4>::create();
+    vec.dedup();
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+}
+
+#[test]
+fn dedup_by_key() {
+    let mut vec: BTreeVec<u32, 6> = (0..500).collect();
+    vec.dedup_by_key(|n| *n / 10);
+    assert_matches(&vec, (0..500).step_by(10));
+}
+
+#[test]
+fn dedup_by() {
+    let mut vec: BTreeVec<(u32, u32), 4> = (0..100).map(|n| (n, 0)).collect();
+    vec.dedup_by(|a, b| {
+        if a.0 / 5 != b.0 / 5 {
+            return false;
+        }
+        b.1 += 1;
+        true
+    });
+    assert_matches(&vec, (0..100).step_by(5).map(|n| (n, 4)));
+}
+
+#[test]
+fn dedup_drops() {
+    let drops = DropCounter::new();
+    let mut vec: BTreeVec<_, 5> =
+        (0..200).map(|n| drops.item(n / 4)).collect();
+    vec.dedup_by_key(|item| item.value);
+    validate(&vec);
+    assert!(drops.get() == 150);
+    drop(vec);
+    assert!(drops.get() == 200);
+}
+
+#[test]
+fn dedup_panic() {
+    let mut vec: BTreeVec<u32, 5> = (0..200).map(|n| n / 2).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.dedup_by(|a, b| {
+            assert!(*a != 50);
+            a == b
+        });
+    }));
+    assert!(result.is_err());
+    assert_matches(&vec, (0..50).chain((100..200).map(|n| n / 2)));
+}
@@ -0,0 +1,154 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches, validate};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn full() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    assert!(vec.drain(..).eq(0..100));
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+    vec.push(1);
+    assert_matches(&vec, 1..2);
+}
+
+#[test]
+fn empty_range() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    assert!(vec.drain(50..50).next().is_none());
+    assert!(vec.drain(100..).next().is_none());
+    assert_matches(&vec, 0..100);
+}
+
+#[test]
+fn ranges() {
+    for (start, end) in [(0, 10), (10, 20), (0, 499), (1, 500), (123, 456)] {
+        let mut vec: BTreeVec<u32, 5> = (0..500).collect();
+        let drain = vec.drain(start..end);
+        assert!(drain.len() == end - start);
+        assert!(drain.eq(start as u32..end as u32));
+        assert!(vec.len() == 500 - (end - start));
+        assert_matches(&vec, (0..start as u32).chain(end as u32..500));
+    }
+}
+
+#[test]
+fn inclusive() {
+    let mut vec: BTreeVec<u32, 6> = (0..50).collect();
+    assert!(vec.drain(10..=19).eq(10..20));
+    assert_matches(&vec, (0..10).chain(20..50));
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.drain(5..11);
+}
+
+#[test]
+fn partial() {
+    let drops = DropCounter::new();
+    let mut vec: BTreeVec<_, 4> = (0..100).map(|n| drops.item(n)).collect();
+    let mut drain = vec.drain(20..80);
+    drain.nth(9);
+    assert!(drops.get() == 10);
+    drop(drain);
+    validate(&vec);
+    assert!(drops.get() == 60);
+    assert!(vec.len() == 40);
+}
+
+#[test]
+fn reversed() {
+    for (start, end) in [(0, 0), (0, 3), (10, 20), (0, 500), (123, 456)] {
+        let mut vec: BTreeVec<u32, 4> = (0..500).collect();
+        let drain = vec.drain(start..end);
+        assert!(drain.rev().eq((start as u32..end as u32).rev()));
+        assert_matches(&vec, (0..start as u32).chain(end as u32..500));
+    }
+}
+
+#[test]
+fn both_ends() {
+    for (start, end) in [(0, 1), (0, 9), (7, 300), (0, 500)] {
+        let mut vec: BTreeVec<u32, 5> = (0..500).collect();
+        let mut drain = vec.drain(start..end);
+        let mut expected: Vec<_> = (start as u32..end as u32).collect();
+        let mut i = 0;
+        while !expected.is_empty() {
+            let (item, expected_item) = if i % 3 == 0 {
+                (drain.next(), Some(expected.remove(0)))
+            } else {
+                (drain.next_back(), expected.pop())
+            };
+            assert!(item == expected_item);
+            assert!(drain.len() == expected.len());
+            i += 1;
+        }
+        assert!(drain.next().is_none());
+        assert!(drain.next_back().is_none());
+        drop(drain);
+        assert_matches(&vec, (0..start as u32).chain(end as u32..500));
+    }
+}
+
+#[test]
+fn partial_both_ends() {
+    let drops = DropCounter::new();
+    let mut vec: BTreeVec<_, 4> = (0..100).map(|n| drops.item(n)).collect();
+    let mut drain = vec.drain(20..80);
+    assert!(drain.next().map(|item| item.value) == Some(20));
+    assert!(drain.nth_back(9).map(|item| item.value) == Some(70));
+    assert!(drops.get() == 11);
+    drop(drain);
+    validate(&vec);
+    assert!(drops.get() == 60);
+    assert!(vec.len() == 40);
+}
+
+#[test]
+fn forget() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    let mut drain = vec.drain(10..90);
+    drain.next();
+    core::mem::forget(drain);
+    assert_matches(&vec, (0..10).chain(90..100));
+}
+
+#[test]
+fn panic_in_drop() {
+    let drops = DropCounter::new();
+    let mut vec: BTreeVec<_, 4> = (0..100).map(|n| drops.item(n)).collect();
+    vec[50].panic = true;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.drain(40..90);
+    }));
+    assert!(result.is_err());
+    validate(&vec);
+    assert!(vec.len() == 50);
+    let before = drops.get();
+    drop(vec);
+    assert!(drops.get() == before + 50);
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{assert_matches, validate};
+
+#[test]
+fn basic_from_iter() {
+    let vec: BTreeVec<u8, 7> = (0..8).collect();
+    assert!(vec.len() == 8);
+    assert_matches(&vec, 0..8);
+}
+
+#[test]
+fn large_from_iter() {
+    let vec: BTreeVec<u32, 4> = (0..1000).collect();
+    assert_matches(&vec, 0..1000);
+    for i in 0..1000 {
+        assert!(vec[i as usize] == i);
+    }
+}
+
+#[test]
+fn empty_from_iter() {
+    let vec: BTreeVec<u8> = core::iter::empty().collect();
+    validate(&vec);
+    assert!(vec.is_empty());
+    assert!(vec.iter().next().is_none());
+}
+
+#[test]
+fn extend_after_push() {
+    let mut vec = BTreeVec::<u32, 5>::create();
+    for i in 0..17 {
+        vec.push(i);
+    }
+    vec.extend(17..300);
+    assert!(vec.len() == 300);
+    assert_matches(&vec, 0..300);
+    vec.extend(300..301);
+    vec.push(301);
+    assert_matches(&vec, 0..302);
+}
+
+#[test]
+fn extend_after_remove() {
+    let mut vec: BTreeVec<u32, 6> = (0..100).collect();
+    for _ in 0..60 {
+        vec.remove(10);
+    }
+    vec.extend(100..150);
+    assert_matches(&vec, (0..10).chain(70..150));
+    for i in 0..10 {
+        vec.remove(0);
+        vec.pop();
+        assert_matches(&vec, (i + 1..10).chain(70..149 - i));
+    }
+    assert_matches(&vec, 70..140);
+}
+
+#[test]
+fn extend_refs() {
+    let items: Vec<u8> = (0..50).collect();
+    let mut vec = BTreeVec::<u8, 8>::create();
+    vec.extend(&items);
+    vec.extend(&items[..10]);
+    assert_matches(&vec, (0..50).chain(0..10));
+}
+
+#[test]
+fn extend_boxes() {
+    let mut vec: BTreeVec<_, 4> = (0..30_u32).map(Box::new).collect();
+    vec.extend((30..90).map(Box::new));
+    assert_matches(&vec, (0..90).map(Box::new));
+    assert!(vec.into_iter().map(|b| *b).eq(0..90));
+}
@@ -0,0 +1,51 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+
+#[test]
+fn ranges() {
+    for (start, end) in [(0, 0), (0, 10), (10, 20), (1, 500), (0, 500)] {
+        let mut vec: BTreeVec<u32, 5> = (0..500).collect();
+        vec.extend_from_within(start..end);
+        assert_matches(&vec, (0..500).chain(start as u32..end as u32));
+        vec.insert(0, 1000);
+        assert!(vec.len() == 501 + end - start);
+    }
+}
+
+#[test]
+fn repeated() {
+    let mut vec: BTreeVec<Box<u32>, 4> = (0..3).map(Box::new).collect();
+    for i in 1..=8 {
+        vec.extend_from_within(..);
+        let expected = (0..1 << i).flat_map(|_| 0..3).map(Box::new);
+        assert_matches(&vec, expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.extend_from_within(5..11);
+}
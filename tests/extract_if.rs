@@ -0,0 +1,110 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+use std::cell::Cell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn all() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    let removed: Vec<_> = vec.extract_if(.., |n| *n % 3 == 0).collect();
+    assert!(removed.iter().copied().eq((0..100).filter(|n| n % 3 == 0)));
+    assert_matches(&vec, (0..100).filter(|n| n % 3 != 0));
+    vec.push(100);
+    assert!(vec.len() == 67);
+}
+
+#[test]
+fn ranges() {
+    for (start, end) in [(0, 0), (0, 10), (10, 20), (1, 500), (123, 456)] {
+        let mut vec: BTreeVec<u32, 5> = (0..500).collect();
+        let range = start as u32..end as u32;
+        let removed: Vec<_> =
+            vec.extract_if(start..end, |n| *n % 2 == 1).collect();
+        assert!(removed.into_iter().eq(range.clone().filter(|n| n % 2 == 1)));
+        let expected = (0..500).filter(|n| !range.contains(n) || n % 2 == 0);
+        assert_matches(&vec, expected);
+    }
+}
+
+#[test]
+fn mutate() {
+    let mut vec: BTreeVec<u32, 6> = (0..50).collect();
+    let removed: Vec<_> = vec
+        .extract_if(10..40, |n| {
+            *n *= 2;
+            *n % 3 == 0
+        })
+        .collect();
+    assert!(
+        removed.into_iter().eq((20..80).step_by(2).filter(|n| n % 3 == 0))
+    );
+    let middle = (20..80).step_by(2).filter(|n| n % 3 != 0);
+    assert_matches(&vec, (0..10).chain(middle).chain(40..50));
+}
+
+#[test]
+fn partial() {
+    let mut vec: BTreeVec<Box<u32>, 4> = (0..100).map(Box::new).collect();
+    let visited = Cell::new(0);
+    let mut iter = vec.extract_if(20..80, |n| {
+        visited.set(visited.get() + 1);
+        **n % 2 == 0
+    });
+    assert!(iter.nth(4).map(|n| *n) == Some(28));
+    drop(iter);
+    assert!(visited.get() == 9);
+    let expected = (0..100).filter(|n| !(20..29).contains(n) || n % 2 == 1);
+    assert_matches(&vec, expected.map(Box::new));
+}
+
+#[test]
+fn forget() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    let mut iter = vec.extract_if(10..90, |_| true);
+    iter.next();
+    core::mem::forget(iter);
+    assert_matches(&vec, 0..10);
+}
+
+#[test]
+fn panic_in_filter() {
+    let mut vec: BTreeVec<u32, 5> = (0..200).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.extract_if(50..150, |n| {
+            assert!(*n != 100);
+            *n % 2 == 0
+        })
+        .for_each(drop);
+    }));
+    assert!(result.is_err());
+    let expected = (0..200).filter(|n| !(50..100).contains(n) || n % 2 == 1);
+    assert_matches(&vec, expected);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.extract_if(5..11, |_| true);
+}
@@ -1,5 +1,5 @@
 /*
- * Copyright (C) 2021 taylor.fish <contact@taylor.fish>
+ * Copyright (C) 2021, 2026 taylor.fish <contact@taylor.fish>
  *
  * This file is part of btree-vec.
  *
@@ -17,7 +17,10 @@
  * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod common;
+
 use btree_vec::BTreeVec;
+use common::assert_matches;
 
 #[test]
 fn basic_push() {
@@ -144,3 +147,56 @@ fn large_insert_middle() {
         assert!(vec[i] == v[i]);
     }
 }
+
+#[test]
+fn insert_iter() {
+    for index in [0, 1, 50, 99, 100] {
+        let mut vec: BTreeVec<u32, 5> = (0..100).collect();
+        vec.insert_iter(index, 1000..1300);
+        assert!(vec.len() == 400);
+        let i = index as u32;
+        assert_matches(&vec, (0..i).chain(1000..1300).chain(i..100));
+    }
+}
+
+#[test]
+fn insert_iter_empty() {
+    let mut vec = BTreeVec::<u32, 4>::create();
+    vec.insert_iter(0, core::iter::empty());
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+    vec.insert_iter(0, 0..10);
+    assert_matches(&vec, 0..10);
+    vec.insert_iter(5, core::iter::empty());
+    assert_matches(&vec, 0..10);
+}
+
+#[test]
+fn repeated_insert_iter() {
+    let mut vec = BTreeVec::<usize, 6>::create();
+    let mut expected = Vec::new();
+    for i in 0..100 {
+        let index = (i * 7) % (expected.len() + 1);
+        let items = i * 100..i * 100 + i % 13;
+        vec.insert_iter(index, items.clone());
+        expected.splice(index..index, items);
+        assert_matches(&vec, &expected);
+    }
+}
+
+#[test]
+fn insert_slice() {
+    let mut vec: BTreeVec<_, 4> = (0..20_u32).map(Box::new).collect();
+    let items: Vec<_> = (100..150_u32).map(Box::new).collect();
+    vec.insert_slice(10, &items);
+    let expected = (0..10).chain(100..150).chain(10..20);
+    assert_matches(&vec, expected.clone().map(Box::new));
+    assert!(vec.into_iter().map(|b| *b).eq(expected));
+}
+
+#[test]
+#[should_panic]
+fn insert_iter_out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.insert_iter(11, 0..1);
+}
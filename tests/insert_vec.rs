@@ -0,0 +1,87 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+
+fn check(nodes: bool) {
+    for index in [0, 1, 10, 250, 499, 500] {
+        for len in [0, 1, 3, 100, 1000] {
+            let mut vec: BTreeVec<u32, 4> = (0..500).collect();
+            let other: BTreeVec<u32, 4> = (1000..1000 + len).collect();
+            if nodes {
+                vec.insert_vec_nodes(index, other);
+            } else {
+                vec.insert_vec(index, other);
+            }
+            let index = index as u32;
+            let expected =
+                (0..index).chain(1000..1000 + len).chain(index..500);
+            assert_matches(&vec, expected);
+            vec.insert(0, 5000);
+            assert!(vec.remove(0) == 5000);
+        }
+    }
+}
+
+#[test]
+fn insert_vec() {
+    check(false);
+}
+
+#[test]
+fn insert_vec_nodes() {
+    check(true);
+}
+
+#[test]
+fn empty() {
+    let mut vec = BTreeVec::<u32, 5>::create();
+    vec.insert_vec_nodes(0, (0..100).collect());
+    assert_matches(&vec, 0..100);
+    let mut other = BTreeVec::<u32, 5>::create();
+    other.push(1);
+    other.pop();
+    vec.insert_vec_nodes(50, other);
+    assert_matches(&vec, 0..100);
+    vec.insert_vec(100, BTreeVec::create());
+    assert_matches(&vec, 0..100);
+}
+
+#[test]
+fn boxes() {
+    let mut vec: BTreeVec<Box<u32>, 6> = (0..100).map(Box::new).collect();
+    let other: BTreeVec<_, 6> = (100..200).map(Box::new).collect();
+    vec.insert_vec_nodes(50, other);
+    let expected = (0..50).chain(100..200).chain(50..100);
+    assert_matches(&vec, expected.map(Box::new));
+    let other: BTreeVec<_, 6> = (200..210).map(Box::new).collect();
+    vec.insert_vec(0, other);
+    let expected = (200..210).chain(0..50).chain(100..200).chain(50..100);
+    assert_matches(&vec, expected.map(Box::new));
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.insert_vec(11, BTreeVec::create());
+}
@@ -0,0 +1,139 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn map() {
+    let vec: BTreeVec<u32, 4> = (0..500).collect();
+    let mapped = vec.map(|x| Box::new(x as u64 * 2));
+    assert!(mapped.len() == 500);
+    assert_matches(&mapped, (0..500).map(|x| Box::new(x * 2)));
+    let vec = BTreeVec::<u32, 4>::create().map(|x| x + 1);
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+}
+
+#[test]
+fn map_order() {
+    let mut vec: BTreeVec<u32, 5> = BTreeVec::create();
+    for i in 0..300 {
+        vec.insert(i / 2, i as u32);
+    }
+    let expected: Vec<_> = vec.iter().copied().collect();
+    let mut seen = Vec::new();
+    let mapped = vec.map(|x| {
+        seen.push(x);
+        x
+    });
+    assert!(seen == expected);
+    assert_matches(&mapped, &expected);
+}
+
+#[test]
+fn try_map() {
+    let vec: BTreeVec<u32, 6> = (0..200).collect();
+    let result = vec.try_map(u8::try_from);
+    assert_matches(&result.unwrap(), 0..200);
+
+    let drops = DropCounter::new();
+    let vec: BTreeVec<_, 4> = (0..100).map(|i| drops.item(i)).collect();
+    let mut calls = 0;
+    let result = vec.try_map(|x| {
+        calls += 1;
+        if x.value == 40 {
+            Err(x.value)
+        } else {
+            Ok(Box::new(x.value))
+        }
+    });
+    assert!(result.err() == Some(40));
+    assert!(calls == 41);
+    assert!(drops.get() == 100);
+}
+
+#[test]
+fn map_ref() {
+    let vec: BTreeVec<String, 4> = (0..200).map(|i| i.to_string()).collect();
+    let lengths = vec.map_ref(|s| s.len());
+    assert!(lengths.len() == 200);
+    assert_matches(&lengths, vec.iter().map(|s| s.len()));
+    assert!(vec.iter().cloned().eq((0..200).map(|i| i.to_string())));
+}
+
+#[test]
+fn panic_in_map() {
+    let drops = DropCounter::new();
+    let vec: BTreeVec<_, 4> = (0..100).map(|i| drops.item(i)).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.map(|x| {
+            if x.value == 70 {
+                panic!("map panicked");
+            }
+            x.value
+        })
+    }));
+    assert!(result.is_err());
+    assert!(drops.get() == 100);
+}
+
+#[test]
+fn panic_in_map_ref() {
+    let drops = DropCounter::new();
+    let vec: BTreeVec<u32, 4> = (0..100).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.map_ref(|&x| {
+            if x == 70 {
+                panic!("map panicked");
+            }
+            drops.item(x)
+        })
+    }));
+    assert!(result.is_err());
+    assert!(drops.get() == 70);
+}
+
+#[test]
+fn error_in_try_map() {
+    let drops = DropCounter::new();
+    let vec: BTreeVec<u32, 4> = (0..100).collect();
+    let result = vec.try_map(|x| {
+        if x == 70 {
+            Err(x)
+        } else {
+            Ok(drops.item(x))
+        }
+    });
+    assert!(result.is_err());
+    assert!(drops.get() == 70);
+}
+
+#[test]
+fn clone_is_independent() {
+    let vec: BTreeVec<u32, 4> = (0..100).collect();
+    let clone = vec.clone();
+    let mut vec = vec;
+    vec.iter_mut().for_each(|x| *x += 1000);
+    drop(vec);
+    assert_matches(&clone, 0..100);
+}
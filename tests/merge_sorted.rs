@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches, validate};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn basic() {
+    let a: BTreeVec<u32, 4> = (0..100).map(|i| i * 2).collect();
+    let b: BTreeVec<u32, 4> = (0..150).map(|i| i * 3).collect();
+    let mut expected: Vec<_> = a.iter().chain(b.iter()).copied().collect();
+    expected.sort();
+    let vec = a.merge_sorted(b);
+    assert!(vec.len() == 250);
+    assert_matches(&vec, &expected);
+}
+
+#[test]
+fn empty() {
+    let a: BTreeVec<u32, 5> = (0..20).collect();
+    let vec = a.merge_sorted(BTreeVec::create());
+    assert_matches(&vec, 0..20);
+    let b: BTreeVec<u32, 5> = (0..20).collect();
+    let vec = BTreeVec::create().merge_sorted(b);
+    assert_matches(&vec, 0..20);
+    let vec = BTreeVec::<u32, 5>::create().merge_sorted(BTreeVec::create());
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+}
+
+#[test]
+fn stable() {
+    let a: BTreeVec<(u32, u32), 4> = (0..60).map(|i| (i / 3, 0)).collect();
+    let b: BTreeVec<(u32, u32), 4> = (0..60).map(|i| (i / 4, 1)).collect();
+    let vec = a.merge_sorted_by(b, |x, y| x.0.cmp(&y.0));
+    validate(&vec);
+    assert!(vec.len() == 120);
+    for w in vec.iter().collect::<Vec<_>>().windows(2) {
+        assert!(w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 <= w[1].1));
+    }
+}
+
+#[test]
+fn boxed() {
+    let a: BTreeVec<Box<u32>, 6> =
+        (0..50).map(|i| Box::new(i * 2 + 1)).collect();
+    let b: BTreeVec<Box<u32>, 6> = (0..50).map(|i| Box::new(i * 2)).collect();
+    let vec = a.merge_sorted(b);
+    assert_matches(&vec, (0..100).map(Box::new));
+}
+
+#[test]
+fn panic_in_compare() {
+    let drops = DropCounter::new();
+    let a: BTreeVec<_, 4> = (0..50).map(|i| drops.item(i)).collect();
+    let b: BTreeVec<_, 4> = (0..50).map(|i| drops.item(i)).collect();
+    let mut calls = 0;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        a.merge_sorted_by(b, |x, y| {
+            calls += 1;
+            if calls == 30 {
+                panic!("compare panicked");
+            }
+            x.value.cmp(&y.value)
+        })
+    }));
+    assert!(result.is_err());
+    assert!(drops.get() == 100);
+}
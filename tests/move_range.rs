@@ -0,0 +1,66 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+use std::ops::Range;
+
+fn check<const B: usize>(len: u32, src: Range<usize>, dest: usize) {
+    let mut vec: BTreeVec<u32, B> = (0..len).collect();
+    let mut expected: Vec<u32> = (0..len).collect();
+    vec.move_range(src.clone(), dest);
+    let moved: Vec<_> = expected.drain(src).collect();
+    expected.splice(dest..dest, moved);
+    assert_matches(&vec, &expected);
+    vec.insert(dest, 1000);
+    assert!(vec.remove(dest) == 1000);
+}
+
+#[test]
+fn forward() {
+    check::<4>(100, 10..20, 50);
+    check::<5>(500, 0..400, 100);
+    check::<6>(500, 10..490, 20);
+    check::<4>(100, 0..1, 99);
+}
+
+#[test]
+fn backward() {
+    check::<4>(100, 50..60, 10);
+    check::<5>(500, 100..500, 0);
+    check::<6>(500, 11..491, 10);
+    check::<4>(100, 99..100, 0);
+}
+
+#[test]
+fn trivial() {
+    check::<4>(100, 10..10, 0);
+    check::<4>(100, 10..90, 10);
+    check::<4>(100, 0..100, 0);
+    check::<4>(0, 0..0, 0);
+}
+
+#[test]
+#[should_panic]
+fn dest_out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.move_range(0..5, 6);
+}
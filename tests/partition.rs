@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches, validate};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn partition_off() {
+    let mut vec: BTreeVec<u32, 4> = (0..500).collect();
+    let other = vec.partition_off(|x| x % 3 == 0);
+    assert_matches(&vec, (0..500).filter(|x| x % 3 == 0));
+    assert_matches(&other, (0..500).filter(|x| x % 3 != 0));
+}
+
+#[test]
+fn partition_into() {
+    let vec: BTreeVec<u32, 5> = (0..300).collect();
+    let (a, b) = vec.partition_into(|&x| (100..150).contains(&x));
+    assert_matches(&a, 100..150);
+    assert_matches(&b, (0..100).chain(150..300));
+}
+
+#[test]
+fn all_or_none() {
+    let mut vec: BTreeVec<u32, 6> = (0..100).collect();
+    let other = vec.partition_off(|_| true);
+    assert_matches(&vec, 0..100);
+    assert!(other.is_empty());
+    assert_matches(&other, 0..0);
+    let other = vec.partition_off(|_| false);
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+    assert_matches(&other, 0..100);
+    let (a, b) = BTreeVec::<u32, 6>::create().partition_into(|_| true);
+    assert!(a.is_empty() && b.is_empty());
+    assert_matches(&a, 0..0);
+    assert_matches(&b, 0..0);
+}
+
+#[test]
+fn boxed() {
+    let mut vec: BTreeVec<Box<u32>, 7> = (0..200).map(Box::new).collect();
+    let other = vec.partition_off(|b| **b % 2 == 0);
+    assert_matches(&vec, (0..200).step_by(2).map(Box::new));
+    assert_matches(&other, (1..200).step_by(2).map(Box::new));
+}
+
+#[test]
+fn panic_in_pred() {
+    let drops = DropCounter::new();
+    let mut vec: BTreeVec<_, 4> = (0..100).map(|i| drops.item(i)).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.partition_off(|x| {
+            if x.value == 60 {
+                panic!("pred panicked");
+            }
+            x.value % 2 == 0
+        })
+    }));
+    assert!(result.is_err());
+    validate(&vec);
+    assert!(drops.get() == 0);
+    assert!(vec.len() == 100);
+    let mut items: Vec<_> = vec.iter().map(|x| x.value).collect();
+    items.sort();
+    assert!(items.into_iter().eq(0..100));
+    drop(vec);
+    assert!(drops.get() == 100);
+}
@@ -0,0 +1,90 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{assert_matches, validate};
+use std::rc::Rc;
+
+#[test]
+fn resize() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.resize(200, 7);
+    assert_matches(&vec, (0..10).chain([7; 190]));
+    vec.resize(5, 8);
+    assert_matches(&vec, 0..5);
+    vec.resize(5, 8);
+    assert_matches(&vec, 0..5);
+    vec.resize(0, 8);
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+    vec.resize(3, 9);
+    assert_matches(&vec, [9; 3]);
+}
+
+#[test]
+fn resize_clones() {
+    let value = Rc::new(());
+    let mut vec = BTreeVec::<_, 5>::create();
+    vec.resize(100, value.clone());
+    validate(&vec);
+    assert!(Rc::strong_count(&value) == 101);
+    vec.resize(40, value.clone());
+    validate(&vec);
+    assert!(Rc::strong_count(&value) == 41);
+    drop(vec);
+    assert!(Rc::strong_count(&value) == 1);
+}
+
+#[test]
+fn resize_with() {
+    let mut vec: BTreeVec<u32, 6> = (0..50).collect();
+    let mut n = 50;
+    vec.resize_with(300, || {
+        n += 1;
+        n - 1
+    });
+    assert_matches(&vec, 0..300);
+    vec.resize_with(20, || unreachable!());
+    assert_matches(&vec, 0..20);
+    vec.insert(10, 1000);
+    assert!(vec.len() == 21);
+}
+
+#[test]
+fn fill() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    vec.fill(3);
+    assert_matches(&vec, [3; 100]);
+    let mut vec = BTreeVec::<u32, 4>::create();
+    vec.fill(3);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn fill_with() {
+    let mut vec: BTreeVec<u32, 7> = std::iter::repeat(0).take(500).collect();
+    let mut n = 0;
+    vec.fill_with(|| {
+        n += 2;
+        n
+    });
+    assert_matches(&vec, (1..=500).map(|n| n * 2));
+}
@@ -0,0 +1,101 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches, validate};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn retain() {
+    let mut vec: BTreeVec<u32, 5> = (0..500).collect();
+    vec.retain(|n| n % 3 == 0);
+    assert_matches(&vec, (0..500).filter(|n| n % 3 == 0));
+    vec.push(1000);
+    vec.insert(0, 1001);
+    assert!(vec.len() == 169);
+}
+
+#[test]
+fn retain_none() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    vec.retain(|_| false);
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+    vec.push(1);
+    assert_matches(&vec, 1..2);
+    let mut vec = BTreeVec::<u32, 4>::create();
+    vec.retain(|_| true);
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+}
+
+#[test]
+fn retain_all() {
+    let mut vec: BTreeVec<u32, 6> = (0..300).collect();
+    vec.retain(|_| true);
+    assert_matches(&vec, 0..300);
+}
+
+#[test]
+fn retain_ranges() {
+    let mut vec: BTreeVec<u32, 4> = (0..1000).collect();
+    vec.retain(|n| (100..110).contains(n) || (700..900).contains(n));
+    assert_matches(&vec, (100..110).chain(700..900));
+    for i in 0..100 {
+        assert!(vec.remove(10) == 700 + i);
+    }
+    assert_matches(&vec, (100..110).chain(800..900));
+}
+
+#[test]
+fn retain_mut() {
+    let mut vec: BTreeVec<u32, 7> = (0..100).collect();
+    vec.retain_mut(|n| {
+        *n *= 2;
+        *n % 3 != 0
+    });
+    assert_matches(&vec, (0..100).map(|n| n * 2).filter(|n| n % 3 != 0));
+}
+
+#[test]
+fn retain_drops() {
+    let drops = DropCounter::new();
+    let mut vec: BTreeVec<_, 4> = (0..100).map(|n| drops.item(n)).collect();
+    vec.retain(|item| item.value % 4 != 0);
+    validate(&vec);
+    assert!(drops.get() == 25);
+    drop(vec);
+    assert!(drops.get() == 100);
+}
+
+#[test]
+fn retain_panic() {
+    let mut vec: BTreeVec<u32, 5> = (0..200).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.retain(|&n| {
+            assert!(n != 150);
+            n % 2 == 0
+        });
+    }));
+    assert!(result.is_err());
+    let expected = (0..150).filter(|n| n % 2 == 0).chain(150..200);
+    assert_matches(&vec, expected);
+}
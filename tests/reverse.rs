@@ -0,0 +1,84 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+
+#[test]
+fn reverse() {
+    for len in [0, 1, 4, 5, 17, 100, 1000] {
+        let mut vec: BTreeVec<u32, 4> = (0..len).collect();
+        vec.reverse();
+        assert_matches(&vec, (0..len).rev());
+        assert!((0..len).all(|i| vec[i as usize] == len - 1 - i));
+        vec.reverse();
+        assert_matches(&vec, 0..len);
+    }
+}
+
+#[test]
+fn odd_children() {
+    // Collected vectors have fully packed nodes, so with an odd `B`, most
+    // internal nodes have an odd number of children, and the middle child
+    // keeps its index.
+    for len in [27, 81, 100] {
+        let mut vec: BTreeVec<u32, 3> = (0..len).collect();
+        vec.reverse();
+        assert_matches(&vec, (0..len).rev());
+        assert!((0..len).all(|i| vec[i as usize] == len - 1 - i));
+    }
+    for len in [125, 400] {
+        let mut vec: BTreeVec<u32, 5> = (0..len).collect();
+        vec.reverse();
+        assert_matches(&vec, (0..len).rev());
+        vec.reverse();
+        assert_matches(&vec, 0..len);
+        assert!((0..len).all(|i| vec[i as usize] == i));
+    }
+}
+
+#[test]
+fn modify_after() {
+    let mut vec: BTreeVec<u32, 5> = (0..500).collect();
+    vec.reverse();
+    assert_matches(&vec, (0..500).rev());
+    for i in 0..100 {
+        assert!(vec.remove(200) == 299 - i);
+    }
+    vec.insert(0, 1000);
+    vec.push(1001);
+    assert!(vec[1] == 499);
+    assert!(vec.iter().copied().skip(1).step_by(100).eq([
+        499,
+        399,
+        199,
+        99,
+        1001
+    ]));
+    assert!(vec.len() == 402);
+}
+
+#[test]
+fn boxes() {
+    let mut vec: BTreeVec<Box<u32>, 6> = (0..300).map(Box::new).collect();
+    vec.reverse();
+    assert_matches(&vec, (0..300).rev().map(Box::new));
+}
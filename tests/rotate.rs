@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+
+#[test]
+fn rotate_left() {
+    for mid in [0, 1, 3, 50, 99, 100, 250, 499, 500] {
+        let mut vec: BTreeVec<u32, 4> = (0..500).collect();
+        vec.rotate_left(mid);
+        let mid = mid as u32;
+        assert_matches(&vec, (mid..500).chain(0..mid));
+        vec.insert(1, 1000);
+        assert!(vec.remove(1) == 1000);
+    }
+}
+
+#[test]
+fn rotate_right() {
+    for k in [0, 1, 7, 123, 300] {
+        let mut vec: BTreeVec<u32, 5> = (0..300).collect();
+        vec.rotate_right(k);
+        let mid = 300 - k as u32;
+        assert_matches(&vec, (mid..300).chain(0..mid));
+    }
+}
+
+#[test]
+fn repeated() {
+    let mut vec: BTreeVec<u32, 6> = (0..1000).collect();
+    for i in 1..=100 {
+        vec.rotate_left(10);
+        let mid = i * 10 % 1000;
+        assert_matches(&vec, (mid..1000).chain(0..mid));
+    }
+    assert_matches(&vec, 0..1000);
+    for i in 0..100 {
+        vec.rotate_right(i);
+        let mid = 1000 - i as u32;
+        assert_matches(&vec, (mid..1000).chain(0..mid));
+        vec.rotate_left(i);
+        assert_matches(&vec, 0..1000);
+    }
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.rotate_left(11);
+}
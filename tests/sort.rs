@@ -0,0 +1,119 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches, validate};
+use std::cell::Cell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+fn random(len: usize, seed: u64) -> Vec<u32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 40) as u32 % 1000
+        })
+        .collect()
+}
+
+#[test]
+fn sort() {
+    for len in [0, 1, 5, 37, 100, 1000, 5000] {
+        let items = random(len, len as u64);
+        let mut vec: BTreeVec<u32, 5> = items.iter().copied().collect();
+        let mut expected = items;
+        vec.sort();
+        expected.sort();
+        assert_matches(&vec, &expected);
+        vec.insert(len / 2, 0);
+        assert!(vec.len() == len + 1);
+    }
+}
+
+#[test]
+fn stable() {
+    let items = random(3000, 1);
+    let mut vec: BTreeVec<(u32, usize), 4> =
+        items.iter().map(|&n| n % 10).zip(0..).collect();
+    vec.sort_by_key(|&(key, _)| key);
+    let mut expected: Vec<_> =
+        items.iter().map(|&n| n % 10).zip(0..).collect();
+    expected.sort_by_key(|&(key, _)| key);
+    assert_matches(&vec, &expected);
+}
+
+#[test]
+fn cached_key() {
+    let items = random(2000, 2);
+    let mut vec: BTreeVec<Box<u32>, 6> =
+        items.iter().copied().map(Box::new).collect();
+    let calls = Cell::new(0);
+    vec.sort_by_cached_key(|n| {
+        calls.set(calls.get() + 1);
+        (**n % 7, **n)
+    });
+    assert!(calls.get() == 2000);
+    let mut expected = items;
+    expected.sort_by_key(|n| (n % 7, *n));
+    assert_matches(&vec, expected.into_iter().map(Box::new));
+}
+
+#[test]
+fn unstable() {
+    let items = random(3000, 3);
+    let mut vec: BTreeVec<u32, 7> = items.iter().copied().collect();
+    vec.sort_unstable_by(|a, b| b.cmp(a));
+    let mut expected = items;
+    expected.sort_by(|a, b| b.cmp(a));
+    assert_matches(&vec, &expected);
+    vec.sort_unstable_by_key(|n| n % 100);
+    validate(&vec);
+    let keys: Vec<_> = vec.iter().map(|n| n % 100).collect();
+    assert!(keys.windows(2).all(|w| w[0] <= w[1]));
+    assert!(vec.len() == 3000);
+}
+
+#[test]
+fn panic_in_compare() {
+    let drops = DropCounter::new();
+    let items = random(1000, 4);
+    let mut vec: BTreeVec<_, 4> =
+        items.iter().map(|&n| drops.item(n)).collect();
+    let calls = Cell::new(0);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.sort_by(|a, b| {
+            calls.set(calls.get() + 1);
+            assert!(calls.get() < 5000);
+            a.value.cmp(&b.value)
+        });
+    }));
+    assert!(result.is_err());
+    validate(&vec);
+    assert!(drops.get() == 0);
+    let mut remaining: Vec<_> = vec.iter().map(|item| item.value).collect();
+    remaining.sort();
+    let mut expected = items;
+    expected.sort();
+    assert!(remaining == expected);
+    vec.push(drops.item(0));
+    drop(vec);
+    assert!(drops.get() == 1001);
+}
@@ -0,0 +1,173 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches, validate};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+#[test]
+fn basic() {
+    let mut vec: BTreeVec<u32, 5> = (0..100).collect();
+    let removed: Vec<_> = vec.splice(10..20, 1000..1005).collect();
+    assert!(removed.iter().copied().eq(10..20));
+    assert_matches(&vec, (0..10).chain(1000..1005).chain(20..100));
+}
+
+#[test]
+fn unconsumed() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    vec.splice(50..60, 1000..1100);
+    assert_matches(&vec, (0..50).chain(1000..1100).chain(60..100));
+}
+
+#[test]
+fn partially_consumed() {
+    let mut vec: BTreeVec<u32, 6> = (0..100).collect();
+    let mut splice = vec.splice(90.., [1, 2, 3]);
+    assert!(splice.len() == 10);
+    assert!(splice.next() == Some(90));
+    assert!(splice.len() == 9);
+    drop(splice);
+    assert_matches(&vec, (0..90).chain(1..4));
+}
+
+#[test]
+fn fused() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    let mut splice = vec.splice(8.., 0..2);
+    assert!(splice.by_ref().eq(8..10));
+    assert!(splice.next().is_none());
+    assert!(splice.next().is_none());
+    drop(splice);
+    assert_matches(&vec, (0..8).chain(0..2));
+}
+
+#[test]
+fn reversed() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    assert!(vec.splice(20..70, 500..510).rev().eq((20..70).rev()));
+    assert_matches(&vec, (0..20).chain(500..510).chain(70..100));
+}
+
+#[test]
+fn both_ends() {
+    let mut vec: BTreeVec<u32, 5> = (0..100).collect();
+    let mut splice = vec.splice(10..40, 1000..1050);
+    let mut expected: Vec<_> = (10..40).collect();
+    let mut i = 0;
+    while expected.len() > 10 {
+        let (item, expected_item) = if i % 3 == 0 {
+            (splice.next(), Some(expected.remove(0)))
+        } else {
+            (splice.next_back(), expected.pop())
+        };
+        assert!(item == expected_item);
+        assert!(splice.len() == expected.len());
+        i += 1;
+    }
+    drop(splice);
+    assert_matches(&vec, (0..10).chain(1000..1050).chain(40..100));
+}
+
+#[test]
+fn insert_only() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    assert!(vec.splice(5..5, 100..200).next().is_none());
+    assert_matches(&vec, (0..5).chain(100..200).chain(5..10));
+}
+
+#[test]
+fn remove_only() {
+    let mut vec: BTreeVec<u32, 4> = (0..200).collect();
+    assert!(vec.splice(..150, core::iter::empty()).eq(0..150));
+    assert_matches(&vec, 150..200);
+}
+
+#[test]
+fn whole() {
+    let mut vec: BTreeVec<u32, 7> = (0..200).collect();
+    assert!(vec.splice(.., 300..400).eq(0..200));
+    assert_matches(&vec, 300..400);
+}
+
+#[test]
+fn boxes() {
+    let mut vec: BTreeVec<_, 4> = (0..50_u32).map(Box::new).collect();
+    let mut splice = vec.splice(10..40, (100..110).map(Box::new));
+    assert!(splice.nth(4).map(|b| *b) == Some(14));
+    drop(splice);
+    let expected = (0..10).chain(100..110).chain(40..50);
+    assert_matches(&vec, expected.clone().map(Box::new));
+    assert!(vec.into_iter().map(|b| *b).eq(expected));
+}
+
+#[test]
+fn reused_leaves() {
+    for (removed, added) in [(40_u32, 3), (40, 40), (40, 150), (8, 100)] {
+        let drops = DropCounter::new();
+        let mut vec: BTreeVec<_, 4> =
+            (0..100).map(|n| drops.item(n)).collect();
+        let replace_with = (0..added).map(|n| drops.item(1000 + n));
+        let range = 30..30 + removed as usize;
+        let mut splice = vec.splice(range, replace_with);
+        assert!(splice.next().map(|item| item.value) == Some(30));
+        drop(splice);
+        assert!(drops.get() == removed as usize);
+        validate(&vec);
+        let expected =
+            (0..30).chain(1000..1000 + added).chain(30 + removed..100);
+        assert!(vec.iter().map(|item| item.value).eq(expected));
+    }
+}
+
+#[test]
+fn panic_in_drop() {
+    let drops = DropCounter::new();
+    let mut vec: BTreeVec<_, 4> = (0..100).map(|n| drops.item(n)).collect();
+    vec[70].panic = true;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        vec.splice(40..90, (0..10).map(|n| drops.item(1000 + n)));
+    }));
+    assert!(result.is_err());
+    validate(&vec);
+    assert!(vec.len() == 50);
+    let before = drops.get();
+    drop(vec);
+    assert!(drops.get() == before + 50);
+}
+
+#[test]
+fn panic_in_replace_with() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let replace_with = (1000..).inspect(|&n| assert!(n < 1005));
+        vec.splice(40..90, replace_with);
+    }));
+    assert!(result.is_err());
+    assert_matches(&vec, (0..40).chain(1000..1005).chain(90..100));
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.splice(5..11, 0..1);
+}
@@ -0,0 +1,100 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+
+#[test]
+fn ends() {
+    let mut a: BTreeVec<u32, 4> = (0..100).collect();
+    let b = a.split_off(100);
+    assert!(b.is_empty());
+    assert_matches(&a, 0..100);
+    let b = a.split_off(0);
+    assert!(a.is_empty());
+    assert_matches(&a, 0..0);
+    assert_matches(&b, 0..100);
+    a.push(1);
+    assert_matches(&a, 1..2);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut a: BTreeVec<u32, 4> = (0..10).collect();
+    let _ = a.split_off(11);
+}
+
+#[test]
+fn every_index() {
+    for at in 0..=300 {
+        let mut a: BTreeVec<u32, 5> = (0..300).collect();
+        let b = a.split_off(at);
+        assert!(a.len() == at);
+        assert!(b.len() == 300 - at);
+        assert_matches(&a, 0..at as u32);
+        assert_matches(&b, at as u32..300);
+    }
+}
+
+#[test]
+fn repeated() {
+    let mut a: BTreeVec<u32, 6> = (0..2000).collect();
+    let mut parts = Vec::new();
+    while a.len() > 1 {
+        let at = a.len() / 2;
+        let b = a.split_off(at);
+        assert_matches(&a, 0..at as u32);
+        assert_matches(&b, at as u32..(at + b.len()) as u32);
+        parts.push(b);
+    }
+    for mut part in parts.into_iter().rev() {
+        a.append_nodes(&mut part);
+        assert_matches(&a, 0..a.len() as u32);
+    }
+    assert_matches(&a, 0..2000);
+}
+
+#[test]
+fn modify_halves() {
+    let mut a: BTreeVec<u32, 4> = (0..500).collect();
+    let mut b = a.split_off(123);
+    assert_matches(&a, 0..123);
+    assert_matches(&b, 123..500);
+    for i in 0..100 {
+        assert!(a.remove(0) == i);
+        assert!(b.pop() == Some(499 - i));
+    }
+    a.push(1000);
+    b.insert(0, 1001);
+    assert_matches(&a, (100..123).chain([1000]));
+    assert_matches(&b, [1001].into_iter().chain(123..400));
+}
+
+#[test]
+fn boxes() {
+    let mut a: BTreeVec<_, 4> = (0..100_u32).map(Box::new).collect();
+    let b = a.split_off(37);
+    assert_matches(&a, (0..37).map(Box::new));
+    assert_matches(&b, (37..100).map(Box::new));
+    assert!(a.into_iter().map(|b| *b).eq(0..37));
+    assert!(b.into_iter().map(|b| *b).eq(37..100));
+}
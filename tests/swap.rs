@@ -0,0 +1,87 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+
+#[test]
+fn swap() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    let mut expected: Vec<u32> = (0..100).collect();
+    for (a, b) in [(0, 99), (1, 2), (50, 50), (10, 90), (63, 3)] {
+        vec.swap(a, b);
+        expected.swap(a, b);
+    }
+    assert_matches(&vec, &expected);
+}
+
+#[test]
+fn swap_across_leaves() {
+    // Collected vectors have fully packed leaves, so with `B` = 3, items
+    // `3 * k` through `3 * k + 2` are in the same leaf.
+    let mut vec: BTreeVec<Box<u32>, 3> = (0..30).map(Box::new).collect();
+    let mut expected: Vec<u32> = (0..30).collect();
+    for (a, b) in [(2, 3), (4, 1), (0, 29), (14, 15), (27, 8)] {
+        vec.swap(a, b);
+        expected.swap(a, b);
+        assert_matches(&vec, expected.iter().copied().map(Box::new));
+    }
+}
+
+#[test]
+#[should_panic]
+fn swap_out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.swap(3, 10);
+}
+
+#[test]
+fn swap_remove() {
+    let mut vec: BTreeVec<Box<u32>, 5> = (0..100).map(Box::new).collect();
+    let mut expected: Vec<u32> = (0..100).collect();
+    for index in [0, 98, 50, 96, 3] {
+        assert!(*vec.swap_remove(index) == expected.swap_remove(index));
+        assert_matches(&vec, expected.iter().copied().map(Box::new));
+    }
+    let mut vec: BTreeVec<u32, 5> = (0..1).collect();
+    assert!(vec.swap_remove(0) == 0);
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+}
+
+#[test]
+fn move_item() {
+    let mut vec: BTreeVec<u32, 6> = (0..200).collect();
+    let mut expected: Vec<u32> = (0..200).collect();
+    for (from, to) in [(0, 199), (199, 0), (10, 20), (150, 30), (7, 7)] {
+        vec.move_item(from, to);
+        let item = expected.remove(from);
+        expected.insert(to, item);
+        assert_matches(&vec, &expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn move_item_out_of_bounds() {
+    let mut vec: BTreeVec<u32, 4> = (0..10).collect();
+    vec.move_item(3, 10);
+}
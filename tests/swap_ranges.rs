@@ -0,0 +1,90 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::assert_matches;
+use std::ops::Range;
+
+fn check<const B: usize>(r1: Range<usize>, r2: Range<usize>) {
+    check_with::<B>(r1.clone(), r2.clone(), false);
+    check_with::<B>(r1, r2, true);
+}
+
+fn check_with<const B: usize>(
+    r1: Range<usize>,
+    r2: Range<usize>,
+    nodes: bool,
+) {
+    let mut a: BTreeVec<u32, B> = (0..500).collect();
+    let mut b: BTreeVec<u32, B> = (1000..1300).collect();
+    let mut va: Vec<u32> = (0..500).collect();
+    let mut vb: Vec<u32> = (1000..1300).collect();
+    if nodes {
+        a.swap_ranges_nodes(r1.clone(), &mut b, r2.clone());
+    } else {
+        a.swap_ranges(r1.clone(), &mut b, r2.clone());
+    }
+    let from_b: Vec<_> = vb.drain(r2.clone()).collect();
+    let from_a: Vec<_> = va.splice(r1, from_b).collect();
+    vb.splice(r2.start..r2.start, from_a);
+    assert_matches(&a, &va);
+    assert_matches(&b, &vb);
+    a.push(1);
+    b.insert(0, 2);
+    assert!(a.len() == va.len() + 1);
+    assert!(b.len() == vb.len() + 1);
+}
+
+#[test]
+fn same_length() {
+    check::<4>(10..20, 100..110);
+    check::<5>(0..300, 0..300);
+    check::<6>(123..456, 7..240);
+}
+
+#[test]
+fn different_lengths() {
+    check::<4>(10..20, 100..200);
+    check::<5>(0..500, 50..60);
+    check::<6>(250..250, 0..300);
+    check::<4>(0..500, 0..0);
+}
+
+#[test]
+fn boxes() {
+    let mut a: BTreeVec<Box<u32>, 4> = (0..100).map(Box::new).collect();
+    let mut b = BTreeVec::<Box<u32>, 4>::create();
+    a.swap_ranges(40..60, &mut b, ..);
+    assert_matches(&a, (0..40).chain(60..100).map(Box::new));
+    assert_matches(&b, (40..60).map(Box::new));
+    b.swap_ranges_nodes(5..15, &mut a, 70..80);
+    assert_matches(&a, (0..40).chain(60..90).chain(45..55).map(Box::new));
+    let expected = (40..45).chain(90..100).chain(55..60);
+    assert_matches(&b, expected.map(Box::new));
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut a: BTreeVec<u32, 4> = (0..10).collect();
+    let mut b: BTreeVec<u32, 4> = (0..10).collect();
+    a.swap_ranges(0..5, &mut b, 5..11);
+}
@@ -0,0 +1,77 @@
+/*
+ * Copyright (C) 2026 taylor.fish <contact@taylor.fish>
+ *
+ * This file is part of btree-vec.
+ *
+ * btree-vec is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * btree-vec is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with btree-vec. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod common;
+
+use btree_vec::BTreeVec;
+use common::{DropCounter, assert_matches, validate};
+
+#[test]
+fn clear() {
+    let mut vec: BTreeVec<u32, 4> = (0..100).collect();
+    vec.clear();
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+    vec.clear();
+    assert_matches(&vec, 0..0);
+    vec.extend(0..10);
+    assert_matches(&vec, 0..10);
+}
+
+#[test]
+fn truncate() {
+    let mut vec: BTreeVec<u32, 5> = (0..500).collect();
+    vec.truncate(600);
+    assert_matches(&vec, 0..500);
+    vec.truncate(500);
+    assert_matches(&vec, 0..500);
+    for len in [499, 250, 37, 5, 1] {
+        vec.truncate(len);
+        assert_matches(&vec, 0..len as u32);
+    }
+    vec.truncate(0);
+    assert!(vec.is_empty());
+    assert_matches(&vec, 0..0);
+    vec.push(1);
+    assert_matches(&vec, 1..2);
+}
+
+#[test]
+fn truncate_every_len() {
+    for len in 0..=200 {
+        let mut vec: BTreeVec<u32, 4> = (0..200).collect();
+        vec.truncate(len);
+        assert!(vec.len() == len);
+        assert_matches(&vec, 0..len as u32);
+        vec.extend(len as u32..300);
+        assert_matches(&vec, 0..300);
+    }
+}
+
+#[test]
+fn drops() {
+    let drops = DropCounter::new();
+    let mut vec: BTreeVec<_, 6> = (0..100).map(|n| drops.item(n)).collect();
+    vec.truncate(60);
+    validate(&vec);
+    assert!(drops.get() == 40);
+    vec.clear();
+    validate(&vec);
+    assert!(drops.get() == 100);
+}